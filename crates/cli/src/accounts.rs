@@ -1,8 +1,15 @@
 use {
     crate::{
         idl::{Idl, IdlEnumField},
-        legacy_idl::LegacyIdl,
-        util::{idl_type_to_rust_type, is_big_array},
+        instructions::Discriminator,
+        legacy_idl::{LegacyIdl, LegacyIdlType, LegacyIdlTypeDefinitionField},
+        util::{
+            big_array_attribute,
+            idl_type_to_rust_type,
+            is_big_array,
+            legacy_fixed_size,
+            matches_filter,
+        },
     },
     askama::Template,
     heck::{ToSnakeCase, ToUpperCamelCase},
@@ -14,11 +21,32 @@ use {
 pub struct AccountData {
     pub struct_name: String,
     pub module_name: String,
-    pub discriminator: String,
+    pub discriminator: Discriminator,
+    /// Offset of the discriminator in the account data.
+    pub discriminator_offset: usize,
+    /// Exact data length identifying the account.
+    pub size: Option<usize>,
     pub fields: Vec<FieldData>,
     pub requires_imports: bool,
 }
 
+impl AccountData {
+    /// Whether account data can be told apart from other accounts' data.
+    pub fn is_identifiable(&self) -> bool {
+        !self.discriminator.0.is_empty() || self.size.is_some()
+    }
+
+    /// Generated types don't implement `Default`, and arrays only do up to 32
+    /// elements.
+    pub fn derives_default(&self) -> bool {
+        !self.requires_imports
+            && !self
+                .fields
+                .iter()
+                .any(|field| is_big_array(&field.rust_type))
+    }
+}
+
 #[allow(dead_code)]
 #[derive(Debug)]
 pub struct FieldData {
@@ -37,14 +65,23 @@ pub struct AccountsStructTemplate<'a> {
 #[template(path = "accounts_mod.askama", escape = "none", ext = ".askama")]
 pub struct AccountsModTemplate<'a> {
     pub accounts: &'a Vec<AccountData>,
-    pub decoder_name: String,
     pub program_struct_name: String,
 }
 
-pub fn legacy_process_accounts(idl: &LegacyIdl) -> Vec<AccountData> {
+#[derive(Template)]
+#[template(path = "client.askama", escape = "none", ext = ".askama")]
+pub struct ClientTemplate<'a> {
+    pub accounts: &'a Vec<AccountData>,
+    pub client_name: String,
+}
+
+pub fn legacy_process_accounts(idl: &LegacyIdl, filter: &[String]) -> Vec<AccountData> {
     let mut accounts_data = Vec::new();
 
     for account in &idl.accounts {
+        if !matches_filter(filter, &account.name) {
+            continue;
+        }
         let mut requires_imports = false;
         let module_name = account.name.to_snake_case();
         let struct_name = account.name.to_upper_camel_case();
@@ -60,11 +97,7 @@ pub fn legacy_process_accounts(idl: &LegacyIdl) -> Vec<AccountData> {
                 if rust_type.1 {
                     requires_imports = true;
                 }
-                let attributes = if is_big_array(&rust_type.0) {
-                    Some("#[serde(with = \"serde_big_array::BigArray\")]".to_string())
-                } else {
-                    None
-                };
+                let attributes = big_array_attribute(&rust_type.0);
                 fields.push(FieldData {
                     name: field.name.to_snake_case(),
                     rust_type: rust_type.0,
@@ -77,6 +110,8 @@ pub fn legacy_process_accounts(idl: &LegacyIdl) -> Vec<AccountData> {
             struct_name,
             module_name,
             discriminator,
+            discriminator_offset: 0,
+            size: None,
             fields,
             requires_imports,
         });
@@ -85,10 +120,78 @@ pub fn legacy_process_accounts(idl: &LegacyIdl) -> Vec<AccountData> {
     accounts_data
 }
 
-pub fn process_accounts(idl: &Idl) -> Vec<AccountData> {
+/// Native accounts of a Shank IDL carry no discriminator. They are identified
+/// by a leading `key` enum field with a variant named after the account, or
+/// else by their size when their layout is fixed.
+pub fn shank_process_accounts(idl: &LegacyIdl, filter: &[String]) -> Vec<AccountData> {
+    let mut accounts_data = legacy_process_accounts(idl, filter);
+
+    for account_data in &mut accounts_data {
+        let Some(account) = idl
+            .accounts
+            .iter()
+            .find(|account| account.name.to_upper_camel_case() == account_data.struct_name)
+        else {
+            continue;
+        };
+        let fields = account.type_.fields.as_deref().unwrap_or_default();
+        account_data.discriminator = Discriminator(Vec::new());
+        account_data.size = fields.iter().try_fold(0, |size, field| {
+            Some(size + legacy_fixed_size(idl, &field.type_)?)
+        });
+        if let Some(variant) = fields
+            .first()
+            .and_then(|field| shank_key_variant(idl, field, &account.name))
+        {
+            account_data.discriminator = Discriminator(vec![variant]);
+            account_data.fields.remove(0);
+            account_data.requires_imports = fields[1..]
+                .iter()
+                .any(|field| idl_type_to_rust_type(&field.type_).1);
+        } else if account_data.size.is_none() {
+            eprintln!(
+                "Warning: Account `{}` has neither a key field nor a fixed size, so it is not \
+                 decoded by type",
+                account.name
+            );
+        }
+    }
+
+    accounts_data
+}
+
+/// Index of the variant named after the account in the enum type of a `key`
+/// field.
+fn shank_key_variant(
+    idl: &LegacyIdl,
+    field: &LegacyIdlTypeDefinitionField,
+    account: &str,
+) -> Option<u8> {
+    let defined = match &field.type_ {
+        LegacyIdlType::Defined { defined } if field.name == "key" => defined,
+        LegacyIdlType::DefinedWithName { defined } if field.name == "key" => &defined.name,
+        _ => return None,
+    };
+    let variants = idl
+        .types
+        .iter()
+        .find(|ty| &ty.name == defined)?
+        .type_
+        .variants
+        .as_ref()?;
+    let index = variants
+        .iter()
+        .position(|variant| variant.name.to_upper_camel_case() == account.to_upper_camel_case())?;
+    u8::try_from(index).ok()
+}
+
+pub fn process_accounts(idl: &Idl, filter: &[String]) -> Vec<AccountData> {
     let mut accounts_data = Vec::new();
 
     for account in &idl.accounts {
+        if !matches_filter(filter, &account.name) {
+            continue;
+        }
         let mut requires_imports = false;
         let module_name = account.name.to_snake_case();
         let struct_name = account.name.to_upper_camel_case();
@@ -106,14 +209,7 @@ pub fn process_accounts(idl: &Idl) -> Vec<AccountData> {
                                 if rust_type.1 {
                                     requires_imports = true;
                                 }
-                                let attributes = if is_big_array(&rust_type.0) {
-                                    Some(
-                                        "#[serde(with = \"serde_big_array::BigArray\")]"
-                                            .to_string(),
-                                    )
-                                } else {
-                                    None
-                                };
+                                let attributes = big_array_attribute(&rust_type.0);
                                 account_fields.push(FieldData {
                                     name: field.name.to_snake_case(),
                                     rust_type: rust_type.0,
@@ -125,14 +221,7 @@ pub fn process_accounts(idl: &Idl) -> Vec<AccountData> {
                                 if rust_type.1 {
                                     requires_imports = true;
                                 }
-                                let attributes = if is_big_array(&rust_type.0) {
-                                    Some(
-                                        "#[serde(with = \"serde_big_array::BigArray\")]"
-                                            .to_string(),
-                                    )
-                                } else {
-                                    None
-                                };
+                                let attributes = big_array_attribute(&rust_type.0);
                                 account_fields.push(FieldData {
                                     name: rust_type.0.to_snake_case(),
                                     rust_type: rust_type.0,
@@ -149,6 +238,8 @@ pub fn process_accounts(idl: &Idl) -> Vec<AccountData> {
             struct_name,
             module_name,
             discriminator,
+            discriminator_offset: 0,
+            size: None,
             fields: account_fields,
             requires_imports,
         });
@@ -157,15 +248,34 @@ pub fn process_accounts(idl: &Idl) -> Vec<AccountData> {
     accounts_data
 }
 
-fn legacy_compute_account_discriminator(account_name: &str) -> String {
+pub fn legacy_compute_account_discriminator(account_name: &str) -> Discriminator {
     let mut hasher = Sha256::new();
     let discriminator_input = format!("account:{}", account_name);
     hasher.update(discriminator_input.as_bytes());
     let hash = hasher.finalize();
-    let discriminator_bytes = &hash[..8];
-    format!("0x{}", hex::encode(discriminator_bytes))
+    Discriminator(hash[..8].to_vec())
+}
+
+fn compute_account_discriminator(bytes: &[u8]) -> Discriminator {
+    Discriminator(bytes.to_vec())
 }
 
-fn compute_account_discriminator(bytes: &[u8]) -> String {
-    format!("0x{}", hex::encode(bytes))
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::util::legacy_read_idl};
+
+    #[test]
+    fn test_shank_process_accounts() {
+        let idl = legacy_read_idl(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../idls/shank_vault.json"
+        ))
+        .unwrap();
+        let accounts = shank_process_accounts(&idl, &[]);
+        assert_eq!(accounts[0].discriminator.0, vec![1]);
+        assert_eq!(accounts[0].size, Some(48));
+        assert_eq!(accounts[0].fields[0].name, "authority");
+        assert_eq!(accounts[1].discriminator.0, vec![2]);
+        assert_eq!(accounts[1].size, None);
+    }
 }
@@ -17,6 +17,59 @@ pub enum Commands {
     #[command(name = "parse")]
     #[command(about = "Generate real Rust code from IDLs.")]
     Parse(ParseOptions),
+
+    #[command(name = "generate")]
+    #[command(about = "Generate the encoders of every program listed in a nitrogen.toml.")]
+    Generate(GenerateOptions),
+
+    #[command(name = "inspect")]
+    #[command(about = "Summarize an IDL and flag what the generator doesn't support yet.")]
+    Inspect(InspectOptions),
+
+    #[command(name = "idl", subcommand)]
+    #[command(about = "Work with IDL files.")]
+    Idl(IdlCommands),
+}
+
+#[derive(Subcommand)]
+pub enum IdlCommands {
+    #[command(name = "convert")]
+    #[command(about = "Convert a legacy (pre-0.30) Anchor IDL to the 0.30 spec.")]
+    Convert(ConvertOptions),
+
+    #[command(name = "diff")]
+    #[command(
+        about = "Report the changes between two IDL versions, failing if any breaks the wire \
+                 format."
+    )]
+    Diff(DiffOptions),
+}
+
+#[derive(Parser)]
+pub struct ConvertOptions {
+    #[arg(help = "Path to the legacy IDL json file.")]
+    pub idl: String,
+
+    #[arg(short, long)]
+    #[arg(help = "Path to write the converted IDL to. Printed to stdout if omitted.")]
+    pub output: Option<String>,
+
+    #[arg(short, long)]
+    #[arg(help = "Program address, required if the IDL has no `metadata.address`.")]
+    pub address: Option<String>,
+}
+
+#[derive(Parser)]
+pub struct DiffOptions {
+    #[arg(help = "Path to the old IDL json file.")]
+    pub old: String,
+
+    #[arg(help = "Path to the new IDL json file.")]
+    pub new: String,
+
+    #[arg(long)]
+    #[arg(help = "Print the changes as JSON.")]
+    pub json: bool,
 }
 
 #[derive(Parser)]
@@ -30,12 +83,15 @@ pub struct ParseOptions {
     pub output: String,
 
     #[arg(short = 'c', long = "crate-name")]
-    #[arg(help = "Generate as a crate with the specified name.")]
+    #[arg(
+        help = "Generate as a crate with the specified name. `{program}` is replaced by the \
+                program name, e.g. for Codama additional programs."
+    )]
     pub crate_name: Option<String>,
 
-    #[arg(short, long = "standard", default_value = "anchor")]
-    #[arg(help = "Specify the IDL standard to parse.")]
-    pub standard: IdlStandard,
+    #[arg(short, long = "standard")]
+    #[arg(help = "Specify the IDL standard to parse. Detected from the file contents if omitted.")]
+    pub standard: Option<IdlStandard>,
 
     #[arg(short, long)]
     #[arg(help = "Comma-separated names of defined types to parse as CPI Events.")]
@@ -49,6 +105,59 @@ pub struct ParseOptions {
     #[arg(help = "Comma-separated instruction names to generate (e.g., \
                   deposit_for_burn,deposit_for_burn_with_hook).")]
     pub filter: Option<String>,
+
+    #[arg(long = "filter-accounts")]
+    #[arg(help = "Comma-separated account names to generate (e.g., LocalToken,TokenPair).")]
+    pub filter_accounts: Option<String>,
+
+    #[arg(long = "filter-events")]
+    #[arg(
+        help = "Comma-separated event names whose types are kept when filtering. Types of other \
+                events are pruned once any filter is set."
+    )]
+    pub filter_events: Option<String>,
+
+    #[arg(long = "human-readable")]
+    #[arg(
+        help = "Serialize pubkeys as base58, byte arrays as hex and 64/128-bit integers as \
+                strings with serde."
+    )]
+    pub human_readable: bool,
+
+    #[arg(long)]
+    #[arg(
+        help = "Compare the generated code with the files on disk without writing, printing a \
+                diff and failing if they differ."
+    )]
+    pub check: bool,
+}
+
+#[derive(Parser)]
+pub struct GenerateOptions {
+    #[arg(short, long, default_value = "nitrogen.toml")]
+    #[arg(help = "Path to the project config file.")]
+    pub config: String,
+
+    #[arg(long)]
+    #[arg(
+        help = "Compare the generated code with the files on disk without writing, printing a \
+                diff and failing if they differ."
+    )]
+    pub check: bool,
+}
+
+#[derive(Parser)]
+pub struct InspectOptions {
+    #[arg(help = "Path to the IDL json file.")]
+    pub idl: String,
+
+    #[arg(short, long = "standard")]
+    #[arg(help = "Specify the IDL standard to parse. Detected from the file contents if omitted.")]
+    pub standard: Option<IdlStandard>,
+
+    #[arg(long)]
+    #[arg(help = "Print the report as JSON.")]
+    pub json: bool,
 }
 
 #[derive(Parser)]
@@ -133,14 +242,18 @@ impl fmt::Display for Url {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
 pub enum IdlStandard {
     Anchor,
+    AnchorLegacy,
     Codama,
+    Shank,
 }
 
 impl fmt::Display for IdlStandard {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             IdlStandard::Anchor => write!(f, "anchor"),
+            IdlStandard::AnchorLegacy => write!(f, "anchor-legacy"),
             IdlStandard::Codama => write!(f, "codama"),
+            IdlStandard::Shank => write!(f, "shank"),
         }
     }
 }
@@ -151,8 +264,13 @@ impl std::str::FromStr for IdlStandard {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
             "anchor" => Ok(IdlStandard::Anchor),
+            "anchor-legacy" => Ok(IdlStandard::AnchorLegacy),
             "codama" => Ok(IdlStandard::Codama),
-            _ => Err("Invalid Idl Standard: Must be 'anchor' or 'codama'.".to_string()),
+            "shank" => Ok(IdlStandard::Shank),
+            _ => Err(
+                "Invalid Idl Standard: Must be 'anchor', 'anchor-legacy', 'codama' or 'shank'."
+                    .to_string(),
+            ),
         }
     }
 }
@@ -0,0 +1,399 @@
+use {
+    crate::{
+        commands::{IdlSource, IdlStandard, ParseOptions, Url},
+        error::NitrogenError,
+        handlers::ProgramData,
+        instructions::{InstructionData, Pda, PdaSeed, SeedData},
+    },
+    anyhow::{Result, anyhow, bail},
+    heck::ToSnakeCase,
+    serde::Deserialize,
+    std::{collections::BTreeMap, fs, path::Path, str::FromStr},
+};
+
+/// Project config read by `nitrogen generate`, one `[[program]]` table per
+/// generated encoder.
+///
+/// ```toml
+/// [[program]]
+/// idl = "idls/escrow.json"
+/// output = "encoders"
+/// crate-name = "escrow-encoder"
+/// filter = ["initialize"]
+/// dependencies = ["spl-token"]
+/// export-helpers = true
+///
+/// [program.seeds.initialize]
+/// escrow = [{ const = "escrow" }, { account = "maker" }, { arg = "seed" }]
+///
+/// [program.types]
+/// EscrowTerms = "crate::helpers::EscrowTerms"
+/// ```
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    #[serde(default, rename = "program")]
+    pub programs: Vec<ProgramConfig>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct ProgramConfig {
+    /// Path to the IDL file, relative to the config file.
+    pub idl: Option<String>,
+    /// Program address to fetch the IDL of from `cluster`.
+    pub address: Option<String>,
+    pub cluster: Option<String>,
+    pub standard: Option<String>,
+    /// Output directory, relative to the config file.
+    pub output: String,
+    pub crate_name: Option<String>,
+    /// Directory of the crate in `output`, `crate-name` by default.
+    pub directory: Option<String>,
+    #[serde(default)]
+    pub filter: Vec<String>,
+    #[serde(default)]
+    pub filter_accounts: Vec<String>,
+    #[serde(default)]
+    pub filter_events: Vec<String>,
+    #[serde(default)]
+    pub event_hints: Vec<String>,
+    #[serde(default)]
+    pub human_readable: bool,
+    /// PDA seeds by instruction and account, replacing the IDL's.
+    #[serde(default)]
+    pub seeds: BTreeMap<String, BTreeMap<String, Vec<SeedOverride>>>,
+    /// Rust paths used in place of generating the named types.
+    #[serde(default)]
+    pub types: BTreeMap<String, String>,
+    /// Workspace dependencies added to the crate, for use by its helpers.
+    #[serde(default)]
+    pub dependencies: Vec<String>,
+    /// Re-exports the items of the crate's helpers at its root.
+    #[serde(default)]
+    pub export_helpers: bool,
+}
+
+/// A PDA seed of the config, with exactly one field set, e.g.
+/// `{ const = "vault" }`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SeedOverride {
+    /// UTF-8 bytes of the string.
+    #[serde(rename = "const")]
+    pub constant: Option<String>,
+    pub bytes: Option<Vec<u8>>,
+    pub pubkey: Option<String>,
+    /// Pubkey of another account of the instruction.
+    pub account: Option<String>,
+    /// Instruction argument.
+    pub arg: Option<String>,
+}
+
+impl SeedOverride {
+    fn pda_seed(&self, instruction: &InstructionData) -> Result<PdaSeed> {
+        let seed = match self {
+            SeedOverride {
+                constant: Some(value),
+                bytes: None,
+                pubkey: None,
+                account: None,
+                arg: None,
+            } => value_seed(value.as_bytes().to_vec()),
+            SeedOverride {
+                constant: None,
+                bytes: Some(value),
+                pubkey: None,
+                account: None,
+                arg: None,
+            } => value_seed(value.clone()),
+            SeedOverride {
+                constant: None,
+                bytes: None,
+                pubkey: Some(pubkey),
+                account: None,
+                arg: None,
+            } => expr_seed(format!("solana_pubkey::pubkey!(\"{pubkey}\").as_ref()")),
+            SeedOverride {
+                constant: None,
+                bytes: None,
+                pubkey: None,
+                account: Some(name),
+                arg: None,
+            } => {
+                let name = name.to_snake_case();
+                if !instruction
+                    .accounts
+                    .iter()
+                    .any(|account| account.name == name)
+                {
+                    bail!(
+                        "Seed account `{name}` isn't an account of instruction `{}`",
+                        instruction.module_name
+                    );
+                }
+                expr_seed(format!("{name}.as_ref()"))
+            }
+            SeedOverride {
+                constant: None,
+                bytes: None,
+                pubkey: None,
+                account: None,
+                arg: Some(name),
+            } => {
+                let name = name.to_snake_case();
+                let Some(arg) = instruction.args.iter().find(|arg| arg.name == name) else {
+                    bail!(
+                        "Seed argument `{name}` isn't an argument of instruction `{}`",
+                        instruction.module_name
+                    );
+                };
+                expr_seed(arg_seed_expr(&format!("self.{name}"), &arg.rust_type))
+            }
+            _ => bail!(
+                "Each seed needs exactly one of `const`, `bytes`, `pubkey`, `account` or `arg`"
+            ),
+        };
+        Ok(seed)
+    }
+}
+
+/// Changes applied to a program after it's read from its IDL, and to the
+/// crate generated from it.
+#[derive(Debug, Default)]
+pub struct Overrides {
+    pub seeds: BTreeMap<String, BTreeMap<String, Vec<SeedOverride>>>,
+    pub types: BTreeMap<String, String>,
+    pub dependencies: Vec<String>,
+    pub directory: Option<String>,
+    pub export_helpers: bool,
+}
+
+impl Overrides {
+    pub fn apply(&self, program: &mut ProgramData) -> Result<()> {
+        for (instruction_name, accounts) in &self.seeds {
+            let Some(instruction) = program
+                .instructions
+                .iter_mut()
+                .find(|instruction| instruction.module_name == instruction_name.to_snake_case())
+            else {
+                bail!("Seeds are overridden for unknown instruction `{instruction_name}`");
+            };
+            for (account_name, seeds) in accounts {
+                let pda_seeds = seeds
+                    .iter()
+                    .map(|seed| seed.pda_seed(instruction))
+                    .collect::<Result<Vec<_>>>()?;
+
+                let account_name = account_name.to_snake_case();
+                let Some(account) = instruction
+                    .accounts
+                    .iter_mut()
+                    .find(|account| account.name == account_name)
+                else {
+                    bail!(
+                        "Seeds are overridden for unknown account `{account_name}` of instruction \
+                         `{instruction_name}`"
+                    );
+                };
+                account.pda = Some(Pda {
+                    seeds: pda_seeds,
+                    program: None,
+                });
+            }
+        }
+
+        for (type_name, path) in &self.types {
+            let Some(index) = program
+                .types
+                .iter()
+                .position(|type_data| type_data.name == *type_name)
+            else {
+                eprintln!("Warning: Mapped type `{type_name}` isn't generated");
+                continue;
+            };
+            program.types.remove(index);
+            if path.rsplit("::").next() == Some(type_name.as_str()) {
+                program.imported_types.push(path.clone());
+            } else {
+                program
+                    .imported_types
+                    .push(format!("{path} as {type_name}"));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn value_seed(value: Vec<u8>) -> PdaSeed {
+    PdaSeed {
+        kind: "const".to_string(),
+        value: Some(SeedData { value }),
+        path: None,
+        account: None,
+        param_type: None,
+        expr: None,
+    }
+}
+
+fn expr_seed(expr: String) -> PdaSeed {
+    PdaSeed {
+        kind: "const".to_string(),
+        value: None,
+        path: None,
+        account: None,
+        param_type: None,
+        expr: Some(expr),
+    }
+}
+
+/// Returns the expression borrowing the seed bytes of `value`, a Rust
+/// expression of type `rust_type`.
+fn arg_seed_expr(value: &str, rust_type: &str) -> String {
+    match rust_type {
+        "u8" | "i8" | "u16" | "i16" | "u32" | "i32" | "u64" | "i64" | "u128" | "i128" => {
+            format!("&{value}.to_le_bytes()")
+        }
+        "String" => format!("{value}.as_bytes()"),
+        _ => format!("{value}.as_ref()"),
+    }
+}
+
+pub fn read_config(config_path: &str) -> Result<Config> {
+    let content =
+        fs::read_to_string(config_path).map_err(NitrogenError::io("read", config_path))?;
+    basic_toml::from_str(&content).map_err(|e| anyhow!("Error parsing {config_path}: {e}"))
+}
+
+impl ProgramConfig {
+    /// Options equivalent to `nitrogen parse`, with paths resolved against
+    /// `base_dir`.
+    pub fn into_parse_options(
+        self,
+        base_dir: &Path,
+        check: bool,
+    ) -> Result<(ParseOptions, Overrides)> {
+        let (idl, url) = match (self.idl, self.address) {
+            (Some(idl), None) => (
+                IdlSource::FilePath(base_dir.join(idl).to_string_lossy().to_string()),
+                None,
+            ),
+            (None, Some(address)) => {
+                let Some(cluster) = self.cluster else {
+                    bail!("Program `{address}` needs a `cluster` to fetch its IDL from");
+                };
+                (
+                    IdlSource::ProgramAddress(address),
+                    Some(Url::from_str(&cluster).map_err(|e| anyhow!(e))?),
+                )
+            }
+            _ => bail!("Each program needs exactly one of `idl` or `address`"),
+        };
+        let standard = self
+            .standard
+            .map(|standard| IdlStandard::from_str(&standard).map_err(|e| anyhow!(e)))
+            .transpose()?;
+        let join = |names: Vec<String>| (!names.is_empty()).then(|| names.join(","));
+
+        let options = ParseOptions {
+            idl,
+            output: base_dir.join(self.output).to_string_lossy().to_string(),
+            crate_name: self.crate_name,
+            standard,
+            event_hints: join(self.event_hints),
+            url,
+            filter: join(self.filter),
+            filter_accounts: join(self.filter_accounts),
+            filter_events: join(self.filter_events),
+            human_readable: self.human_readable,
+            check,
+        };
+        let overrides = Overrides {
+            seeds: self.seeds,
+            types: self.types,
+            dependencies: self.dependencies,
+            directory: self.directory,
+            export_helpers: self.export_helpers,
+        };
+        Ok((options, overrides))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            accounts::legacy_process_accounts,
+            instructions::legacy_process_instructions,
+            types::legacy_process_types,
+            util::legacy_read_idl,
+        },
+    };
+
+    const LEGACY_ESCROW: &str =
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../../idls/legacy_escrow.json");
+
+    #[test]
+    fn test_apply_overrides() {
+        let config: Config = basic_toml::from_str(
+            r#"
+            [[program]]
+            idl = "escrow.json"
+            output = "encoders"
+
+            [program.seeds.initialize]
+            escrow = [{ const = "escrow" }, { account = "maker" }, { arg = "seed" }]
+
+            [program.types]
+            EscrowTerms = "crate::helpers::Terms"
+            "#,
+        )
+        .unwrap();
+        let (options, overrides) = config
+            .programs
+            .into_iter()
+            .next()
+            .unwrap()
+            .into_parse_options(Path::new("project"), false)
+            .unwrap();
+        assert_eq!(options.output, "project/encoders");
+
+        let idl = legacy_read_idl(LEGACY_ESCROW).unwrap();
+        let mut program = ProgramData {
+            name: idl.name.clone(),
+            address: String::new(),
+            accounts: legacy_process_accounts(&idl, &[]),
+            instructions: legacy_process_instructions(&idl, &[]).unwrap(),
+            types: legacy_process_types(&idl, None),
+            errors: Vec::new(),
+            events: Vec::new(),
+            imported_types: Vec::new(),
+            program_dependencies: Vec::new(),
+        };
+        overrides.apply(&mut program).unwrap();
+
+        let escrow = &program.instructions[0].accounts[1];
+        let seeds = &escrow.pda.as_ref().unwrap().seeds;
+        assert_eq!(seeds[0].value.as_ref().unwrap().value, b"escrow");
+        assert_eq!(seeds[1].expr.as_deref(), Some("maker.as_ref()"));
+        assert_eq!(seeds[2].expr.as_deref(), Some("&self.seed.to_le_bytes()"));
+        assert!(!escrow.is_param());
+
+        assert!(!program.types.iter().any(|t| t.name == "EscrowTerms"));
+        assert_eq!(program.imported_types, vec![
+            "crate::helpers::Terms as EscrowTerms"
+        ]);
+
+        let seeds = basic_toml::from_str(r#"escrow = [{ arg = "amount" }]"#).unwrap();
+        let overrides = Overrides {
+            seeds: BTreeMap::from([("cancel".to_string(), seeds)]),
+            types: BTreeMap::new(),
+            dependencies: Vec::new(),
+            directory: None,
+            export_helpers: false,
+        };
+        assert!(overrides.apply(&mut program).is_err());
+    }
+}
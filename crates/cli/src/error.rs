@@ -0,0 +1,74 @@
+use {std::io, thiserror::Error};
+
+/// Errors reported by the CLI, each exiting with its own code.
+#[derive(Debug, Error)]
+pub enum NitrogenError {
+    #[error("Couldn't {action} {path}")]
+    Io {
+        action: &'static str,
+        path: String,
+        source: io::Error,
+    },
+
+    #[error(
+        "Error parsing IDL {path} at `{json_path}`{}: {message}",
+        text_position(*.line, *.column)
+    )]
+    IdlParse {
+        path: String,
+        /// Location of the error, e.g. `$.instructions[2].args`.
+        json_path: String,
+        /// 1-based, 0 when the error isn't tied to a position in the text.
+        line: usize,
+        column: usize,
+        message: String,
+    },
+
+    #[error("Unsupported {construct} in `{item}`")]
+    Unsupported { item: String, construct: String },
+
+    #[error("Couldn't render the {template} template")]
+    Template {
+        template: &'static str,
+        source: askama::Error,
+    },
+}
+
+fn text_position(line: usize, column: usize) -> String {
+    match line {
+        0 => String::new(),
+        _ => format!(", line {line} column {column}"),
+    }
+}
+
+impl NitrogenError {
+    pub fn io(action: &'static str, path: &str) -> impl FnOnce(io::Error) -> Self {
+        let path = path.to_string();
+        move |source| NitrogenError::Io {
+            action,
+            path,
+            source,
+        }
+    }
+
+    pub fn template(template: &'static str) -> impl FnOnce(askama::Error) -> Self {
+        move |source| NitrogenError::Template { template, source }
+    }
+
+    pub fn unsupported(item: &str, construct: &str) -> Self {
+        NitrogenError::Unsupported {
+            item: item.to_string(),
+            construct: construct.to_string(),
+        }
+    }
+
+    /// Exit code following `sysexits.h`.
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            NitrogenError::IdlParse { .. } => 65,
+            NitrogenError::Unsupported { .. } => 69,
+            NitrogenError::Template { .. } => 70,
+            NitrogenError::Io { .. } => 74,
+        }
+    }
+}
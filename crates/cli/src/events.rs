@@ -0,0 +1,30 @@
+use {
+    crate::{instructions::Discriminator, types::TypeData},
+    askama::Template,
+    heck::ToSnakeCase,
+};
+
+#[derive(Debug)]
+pub struct EventData {
+    pub type_data: TypeData,
+    pub discriminator: Discriminator,
+}
+
+impl EventData {
+    pub fn module_name(&self) -> String {
+        self.type_data.name.to_snake_case()
+    }
+}
+
+#[derive(Template)]
+#[template(path = "events_struct.askama", escape = "none", ext = ".askama")]
+pub struct EventsStructTemplate<'a> {
+    pub event: &'a EventData,
+}
+
+#[derive(Template)]
+#[template(path = "events_mod.askama", escape = "none", ext = ".askama")]
+pub struct EventsModTemplate<'a> {
+    pub events: &'a Vec<EventData>,
+    pub program_event_enum: String,
+}
@@ -0,0 +1,119 @@
+use {
+    crate::{
+        error::NitrogenError,
+        handlers::{
+            Issue,
+            ProgramData,
+            codama::{
+                processors::{
+                    process_codama_accounts,
+                    process_codama_defined_type,
+                    process_codama_defined_types,
+                    process_codama_errors,
+                    process_codama_instructions,
+                },
+                types::ValueNode,
+                utils::{
+                    get_account_discriminator,
+                    get_instruction_discriminator,
+                    map_type,
+                    read_codama_idl,
+                },
+            },
+        },
+    },
+    anyhow::Result,
+    heck::{ToSnakeCase, ToUpperCamelCase},
+    std::collections::HashSet,
+};
+
+/// Reads the main program of a Codama IDL, flagging the discriminators, type
+/// nodes and PDA seeds the encoder can't be generated from.
+pub fn inspect_codama(path: &str) -> Result<(ProgramData, Vec<Issue>)> {
+    let mut idl = read_codama_idl(path)?;
+    let mut issues = Vec::new();
+
+    // Unsupported items are reported, then left out so the rest is processed.
+    idl.program.instructions.retain_mut(|instruction| {
+        let module_name = instruction.name.to_snake_case();
+        instruction.arguments.retain(|arg| {
+            let item = format!("{module_name}.{}", arg.name.to_snake_case());
+            is_supported(map_type(&item, &arg.arg_type), &mut issues)
+        });
+        is_supported(get_instruction_discriminator(instruction), &mut issues)
+    });
+    idl.program.accounts.retain_mut(|account| {
+        let struct_name = account.name.to_upper_camel_case();
+        account.data.fields.retain(|field| {
+            let item = format!("{struct_name}.{}", field.name.to_snake_case());
+            is_supported(map_type(&item, &field.field_type), &mut issues)
+        });
+        is_supported(
+            get_account_discriminator(account, &account.name),
+            &mut issues,
+        )
+    });
+    idl.program.defined_types.retain(|defined_type| {
+        is_supported(process_codama_defined_type(defined_type), &mut issues)
+    });
+    let program = &idl.program;
+
+    let accounts = process_codama_accounts(program)?;
+    let instructions = process_codama_instructions(program)?;
+    let types = process_codama_defined_types(program, &HashSet::new())?;
+
+    for defined_type in &program.defined_types {
+        let name = defined_type.name.to_upper_camel_case();
+        if !types.iter().any(|type_data| type_data.name == name) {
+            issues.push(Issue {
+                item: name,
+                construct: "type node".to_string(),
+            });
+        }
+    }
+
+    for (instruction_node, instruction) in program.instructions.iter().zip(&instructions) {
+        // PDA defaults whose seeds don't resolve leave the account to the caller.
+        for account_node in &instruction_node.accounts {
+            if !matches!(
+                account_node.default_value,
+                Some(ValueNode::PdaValueNode { .. })
+            ) {
+                continue;
+            }
+            let name = account_node.name.to_snake_case();
+            if instruction
+                .accounts
+                .iter()
+                .any(|account| account.name == name && account.pda.is_none())
+            {
+                issues.push(Issue {
+                    item: format!("{}.{}", instruction.module_name, name),
+                    construct: "PDA seeds".to_string(),
+                });
+            }
+        }
+    }
+
+    let program_data = ProgramData {
+        accounts,
+        instructions,
+        types,
+        errors: process_codama_errors(program),
+        events: Vec::new(),
+        imported_types: Vec::new(),
+        program_dependencies: Vec::new(),
+        name: program.name.clone(),
+        address: program.public_key.clone(),
+    };
+    Ok((program_data, issues))
+}
+
+/// Records an unsupported construct as an issue.
+fn is_supported<T>(result: Result<T, NitrogenError>, issues: &mut Vec<Issue>) -> bool {
+    let Err(NitrogenError::Unsupported { item, construct }) = result else {
+        return true;
+    };
+    issues.push(Issue { item, construct });
+    false
+}
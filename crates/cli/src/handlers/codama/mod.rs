@@ -1,6 +1,7 @@
+mod inspect_codama;
 mod parse_codama;
 mod processors;
 mod types;
 mod utils;
 
-pub use parse_codama::*;
+pub use {inspect_codama::*, parse_codama::*};
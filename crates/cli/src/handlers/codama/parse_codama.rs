@@ -1,213 +1,165 @@
 use {
     crate::{
-        accounts::{AccountsModTemplate, AccountsStructTemplate},
-        handlers::codama::{
-            processors::{
-                process_codama_accounts,
-                process_codama_defined_types,
-                process_codama_instructions,
+        config::Overrides,
+        error::NitrogenError,
+        handlers::{
+            FileChanges,
+            ProgramData,
+            codama::{
+                processors::{
+                    filter_codama_program,
+                    process_codama_accounts,
+                    process_codama_defined_types,
+                    process_codama_errors,
+                    process_codama_events,
+                    process_codama_external_types,
+                    process_codama_instructions,
+                },
+                types::ProgramNode,
+                utils::{parse_event_hints, read_codama_idl},
             },
-            utils::{parse_event_hints, read_codama_idl},
+            generate_encoder,
         },
-        instructions::{InstructionsModTemplate, InstructionsStructTemplate},
-        types::TypeStructTemplate,
-        util::is_big_array,
+        util::parse_filter,
     },
-    anyhow::{Result, bail},
-    askama::Template,
+    anyhow::Result,
     heck::{ToKebabCase, ToSnakeCase, ToUpperCamelCase},
-    std::fs::{self},
+    std::collections::BTreeSet,
 };
 
+/// Generates one encoder per program of the Codama root, the main program
+/// first followed by its `additionalPrograms`. `filter` and
+/// `account_filter` select instructions and accounts of the main program.
 pub fn parse_codama(
     path: String,
     output: String,
     crate_name: Option<String>,
+    filter: Option<String>,
+    account_filter: Option<String>,
     event_hints: Option<String>,
-) -> Result<()> {
-    let (accounts_data, instructions_data, types_data, program_name) = match read_codama_idl(&path)
-    {
-        Ok(idl) => {
-            let accounts_data = process_codama_accounts(&idl.program);
-            let instructions_data = process_codama_instructions(&idl.program);
-
-            let event_hints = parse_event_hints(event_hints);
-            let types_data = process_codama_defined_types(&idl.program, &event_hints);
-            let program_name = idl.program.name;
-
-            (accounts_data, instructions_data, types_data, program_name)
-        }
-        Err(error) => {
-            bail!("Error parsing Codama IDL: {error}");
+    human_readable: bool,
+    check: bool,
+    overrides: &Overrides,
+) -> Result<FileChanges> {
+    let filter = parse_filter(filter);
+    let account_filter = parse_filter(account_filter);
+
+    let mut idl = read_codama_idl(&path)?;
+    let event_hints = parse_event_hints(event_hints);
+    filter_codama_program(&mut idl.program, &filter, &account_filter, &event_hints);
+
+    let programs: Vec<&ProgramNode> = std::iter::once(&idl.program)
+        .chain(&idl.additional_programs)
+        .collect();
+    let crate_names: Vec<Option<String>> = programs
+        .iter()
+        .enumerate()
+        .map(|(index, program)| program_crate_name(crate_name.as_deref(), &program.name, index))
+        .collect();
+
+    for hint in &event_hints {
+        if !programs.iter().any(|program| {
+            program
+                .defined_types
+                .iter()
+                .any(|defined_type| defined_type.name.to_upper_camel_case() == *hint)
+        }) {
+            eprintln!("Warning: Event hint `{}` matches no defined type", hint);
         }
-    };
-
-    let decoder_name = format!("{}Decoder", program_name.to_upper_camel_case());
-    let decoder_name_kebab = program_name.to_kebab_case();
-    let program_struct_name = format!("{}Account", program_name.to_upper_camel_case());
-    let program_instruction_enum = format!("{}Instruction", program_name.to_upper_camel_case());
-
-    let crate_dir = match &crate_name {
-        Some(name) => format!("{}/{}", output.trim_end_matches('/'), name),
-        None => format!(
-            "{}/{}_decoder",
-            output.trim_end_matches('/'),
-            program_name.to_snake_case()
-        ),
-    };
-
-    fs::create_dir_all(&crate_dir).expect("Failed to create decoder directory");
-
-    let src_dir = if crate_name.is_some() {
-        format!("{}/src", crate_dir)
-    } else {
-        crate_dir.clone()
-    };
-
-    fs::create_dir_all(&src_dir).expect("Failed to create src directory");
-
-    let needs_big_array = types_data.iter().any(|type_data| {
-        type_data.fields.iter().any(|field| {
-            field.rust_type.starts_with("[")
-                && field.rust_type.ends_with("]")
-                && is_big_array(&field.rust_type)
-        })
-    });
-
-    // Generate types
-    let types_dir = format!("{}/types", src_dir);
-    fs::create_dir_all(&types_dir).expect("Failed to create types directory");
-
-    for type_data in &types_data {
-        let template = TypeStructTemplate { type_data };
-        let rendered = template
-            .render()
-            .expect("Failed to render type struct template");
-        let filename = format!("{}/{}.rs", types_dir, type_data.name.to_snake_case());
-        fs::write(&filename, rendered).expect("Failed to write type struct file");
-        println!("Generated {}", filename);
     }
 
-    let types_mod_content = types_data
-        .iter()
-        .map(|type_data| {
-            format!(
-                "pub mod {};\npub use {}::*;",
-                type_data.name.to_snake_case(),
-                type_data.name.to_snake_case()
-            )
-        })
-        .collect::<Vec<_>>()
-        .join("\n");
-
-    let types_mod_filename = format!("{}/mod.rs", types_dir);
-    fs::write(&types_mod_filename, types_mod_content).expect("Failed to write types mod file");
-    println!("Generated {}", types_mod_filename);
-
-    // Generate Accounts
-
-    let accounts_dir = format!("{}/accounts", src_dir);
-    fs::create_dir_all(&accounts_dir).expect("Failed to create accounts directory");
+    let mut changes = FileChanges::default();
+    for (index, (program, crate_name)) in programs.iter().zip(&crate_names).enumerate() {
+        let mut imported_types = Vec::new();
+        let mut program_dependencies = BTreeSet::new();
+        for (owner, type_name) in process_codama_external_types(program, &programs) {
+            let owner_index = programs
+                .iter()
+                .position(|other| other.name == owner)
+                .ok_or_else(|| NitrogenError::IdlParse {
+                    path: path.clone(),
+                    json_path: match index {
+                        0 => "$.program".to_string(),
+                        _ => format!("$.additionalPrograms[{}]", index - 1),
+                    },
+                    line: 0,
+                    column: 0,
+                    message: format!("type `{type_name}` links to unknown program `{owner}`"),
+                })?;
+            let type_name = type_name.to_upper_camel_case();
+            match &crate_names[owner_index] {
+                Some(owner_crate) => {
+                    imported_types.push(format!(
+                        "{}::types::{}",
+                        owner_crate.to_snake_case(),
+                        type_name
+                    ));
+                    program_dependencies.insert(owner_crate.clone());
+                }
+                None => imported_types.push(format!(
+                    "super::super::{}_encoder::types::{}",
+                    owner.to_snake_case(),
+                    type_name
+                )),
+            }
+        }
 
-    for account in &accounts_data {
-        let template = AccountsStructTemplate { account };
-        let rendered = template
-            .render()
-            .expect("Failed to render account struct template");
-        let filename = format!("{}/{}.rs", accounts_dir, account.module_name);
-        fs::write(&filename, rendered).expect("Failed to write account struct file");
-        println!("Generated {}", filename);
+        let program_data = ProgramData {
+            accounts: process_codama_accounts(program)?,
+            instructions: process_codama_instructions(program)?,
+            types: process_codama_defined_types(program, &event_hints)?,
+            errors: process_codama_errors(program),
+            events: process_codama_events(program, &event_hints)?,
+            imported_types,
+            program_dependencies: program_dependencies.into_iter().collect(),
+            name: program.name.clone(),
+            address: program.public_key.clone(),
+        };
+        changes.extend(generate_encoder(
+            program_data,
+            output.clone(),
+            crate_name.clone(),
+            human_readable,
+            check,
+            overrides,
+        )?);
     }
 
-    let accounts_mod_template = AccountsModTemplate {
-        accounts: &accounts_data,
-        decoder_name: decoder_name.clone(),
-        program_struct_name: program_struct_name.clone(),
-    };
-    let accounts_mod_rendered = accounts_mod_template
-        .render()
-        .expect("Failed to render accounts mod template");
-    let accounts_mod_filename = format!("{}/mod.rs", accounts_dir);
-
-    fs::write(&accounts_mod_filename, accounts_mod_rendered)
-        .expect("Failed to write accounts mod file");
-    println!("Generated {}", accounts_mod_filename);
-
-    // Generate Instructions
-
-    let instructions_dir = format!("{}/instructions", src_dir);
-    fs::create_dir_all(&instructions_dir).expect("Failed to create instructions directory");
+    Ok(changes)
+}
 
-    for instruction in &instructions_data {
-        let template = InstructionsStructTemplate { instruction };
-        let rendered = template
-            .render()
-            .expect("Failed to render instruction struct template");
-        let filename = format!("{}/{}.rs", instructions_dir, instruction.module_name);
-        fs::write(&filename, rendered).expect("Failed to write instruction struct file");
-        println!("Generated {}", filename);
+/// Crate name of the program at `index`: `{program}` in the template is
+/// replaced by the program name, and additional programs default to
+/// `<program>-encoder` when it's absent.
+fn program_crate_name(template: Option<&str>, program_name: &str, index: usize) -> Option<String> {
+    let template = template?;
+    if template.contains("{program}") {
+        Some(template.replace("{program}", &program_name.to_kebab_case()))
+    } else if index == 0 {
+        Some(template.to_string())
+    } else {
+        Some(format!("{program_name}-encoder").to_kebab_case())
     }
+}
 
-    let instructions_mod_template = InstructionsModTemplate {
-        instructions: &instructions_data,
-        decoder_name: decoder_name.clone(),
-        program_instruction_enum: program_instruction_enum.clone(),
-    };
-    let instructions_mod_rendered = instructions_mod_template
-        .render()
-        .expect("Failed to render instructions mod template");
-    let instructions_mod_filename = format!("{}/mod.rs", instructions_dir);
-
-    fs::write(&instructions_mod_filename, instructions_mod_rendered)
-        .expect("Failed to write instructions mod file");
-
-    println!("Generated {}", instructions_mod_filename);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    if crate_name.is_some() {
-        let lib_rs_content = format!(
-            "pub struct {decoder_name};\npub mod accounts;\npub mod instructions;\npub mod types;",
-            decoder_name = decoder_name
+    #[test]
+    fn test_program_crate_name() {
+        assert_eq!(program_crate_name(None, "splToken", 1), None);
+        assert_eq!(
+            program_crate_name(Some("{program}-client"), "splToken", 1).as_deref(),
+            Some("spl-token-client")
         );
-        let lib_rs_filename = format!("{}/lib.rs", src_dir);
-        fs::write(&lib_rs_filename, lib_rs_content).expect("Failed to write lib.rs file");
-        println!("Generated {}", lib_rs_filename);
-
-        let cargo_toml_content = format!(
-            r#"[package]
-name = "{decoder_name_kebab}-decoder"
-version = "0.10.0"
-edition = {{ workspace = true }}
-
-[lib]
-crate-type = ["rlib"]
-
-[dependencies]
-solana-account = {{ workspace = true }}
-solana-instruction = {{ workspace = true }}
-solana-pubkey = {{ workspace = true }}
-serde = {{ workspace = true }}
-{big_array}
-"#,
-            decoder_name_kebab = decoder_name_kebab,
-            big_array = if needs_big_array {
-                "serde-big-array = { workspace = true }"
-            } else {
-                ""
-            }
+        assert_eq!(
+            program_crate_name(Some("counter-encoder"), "counter", 0).as_deref(),
+            Some("counter-encoder")
         );
-        let cargo_toml_filename = format!("{}/Cargo.toml", crate_dir);
-        fs::write(&cargo_toml_filename, cargo_toml_content)
-            .expect("Failed to write Cargo.toml file");
-        println!("Generated {}", cargo_toml_filename);
-    } else {
-        let mod_rs_content = format!(
-            "pub struct {decoder_name};\npub mod accounts;\npub mod instructions;\npub mod types;",
-            decoder_name = decoder_name
+        assert_eq!(
+            program_crate_name(Some("counter-encoder"), "splToken", 1).as_deref(),
+            Some("spl-token-encoder")
         );
-        let mod_rs_filename = format!("{}/mod.rs", src_dir);
-        fs::write(&mod_rs_filename, mod_rs_content).expect("Failed to write mod.rs file");
-        println!("Generated {}", mod_rs_filename);
     }
-
-    Ok(())
 }
@@ -1,23 +1,51 @@
 use {
     super::{
-        types::{EnumVariantTypeNode, ProgramNode, SignerType, TypeNode},
+        types::{
+            DefinedTypeNode,
+            EnumVariantTypeNode,
+            InstructionAccountNode,
+            InstructionNode,
+            PdaLink,
+            PdaSeedNode,
+            PdaSeedValueNode,
+            ProgramNode,
+            SignerType,
+            TypeNode,
+            ValueNode,
+        },
         utils::{
+            InstructionDiscriminator,
+            constant_pda_seed,
             get_account_discriminator,
+            get_event_discriminator,
             get_instruction_discriminator,
             map_type,
             resolve_struct_type,
+            seed_bytes_expr,
+            visit_defined_type_links,
         },
     },
     crate::{
         accounts::{AccountData, FieldData as AccountFieldData},
-        instructions::{AccountMetaData, ArgumentData, InstructionData},
+        error::NitrogenError,
+        events::EventData,
+        instructions::{
+            AccountMetaData,
+            ArgumentData,
+            Discriminator,
+            InstructionData,
+            Pda,
+            PdaSeed,
+        },
+        program_errors::ErrorData,
         types::{EnumVariantData, EnumVariantFields, FieldData, TypeData, TypeKind},
+        util::{big_array_attribute, matches_filter},
     },
     heck::{ToSnakeCase, ToUpperCamelCase},
-    std::collections::HashSet,
+    std::collections::{BTreeSet, HashSet},
 };
 
-pub fn process_codama_accounts(program: &ProgramNode) -> Vec<AccountData> {
+pub fn process_codama_accounts(program: &ProgramNode) -> Result<Vec<AccountData>, NitrogenError> {
     let mut accounts_data = Vec::new();
 
     for account in &program.accounts {
@@ -25,38 +53,43 @@ pub fn process_codama_accounts(program: &ProgramNode) -> Vec<AccountData> {
 
         let struct_name = account.name.to_upper_camel_case();
         let module_name = account.name.to_snake_case();
-        let discriminator = get_account_discriminator(account, &account.name);
+        let discriminator = get_account_discriminator(account, &account.name)?;
 
         let mut fields = Vec::new();
         for field in &account.data.fields {
-            if field.name == "discriminator" {
+            if discriminator.prefix_fields.contains(&field.name) {
                 continue;
             }
-            let rust_type = map_type(&field.field_type);
+            let name = field.name.to_snake_case();
+            let rust_type = map_type(&format!("{struct_name}.{name}"), &field.field_type)?;
             if rust_type.1 {
                 requires_imports = true;
             }
 
             fields.push(AccountFieldData {
-                name: field.name.to_snake_case(),
-                rust_type: rust_type.0,
-                attributes: None,
+                name,
+                rust_type: rust_type.0.clone(),
+                attributes: big_array_attribute(&rust_type.0),
             });
         }
 
         accounts_data.push(AccountData {
             struct_name,
             module_name,
-            discriminator,
+            discriminator: Discriminator(discriminator.bytes),
+            discriminator_offset: discriminator.offset,
+            size: discriminator.size,
             fields,
             requires_imports,
         });
     }
 
-    accounts_data
+    Ok(accounts_data)
 }
 
-pub fn process_codama_instructions(program: &ProgramNode) -> Vec<InstructionData> {
+pub fn process_codama_instructions(
+    program: &ProgramNode,
+) -> Result<Vec<InstructionData>, NitrogenError> {
     let mut instructions_data = Vec::new();
 
     for instruction in &program.instructions {
@@ -64,166 +97,901 @@ pub fn process_codama_instructions(program: &ProgramNode) -> Vec<InstructionData
 
         let struct_name = instruction.name.to_upper_camel_case();
         let module_name = instruction.name.to_snake_case();
-        let discriminator = crate::instructions::Discriminator(get_instruction_discriminator(
-            &instruction.arguments,
-            &instruction.name,
-        ));
+        let InstructionDiscriminator {
+            prefix,
+            prefix_args,
+            constant_args,
+        } = get_instruction_discriminator(instruction)?;
 
         let mut args = Vec::new();
         for arg in &instruction.arguments {
-            if arg.name == "discriminator" {
+            if prefix_args.contains(&arg.name) {
                 continue;
             }
-            let rust_type = map_type(&arg.arg_type);
+            let constant = constant_args.get(&arg.name);
+            let name = arg.name.to_snake_case();
+            let rust_type = map_type(&format!("{module_name}.{name}"), &arg.arg_type)?;
             if rust_type.1 {
                 requires_imports = true;
             }
             args.push(ArgumentData {
-                name: arg.name.to_snake_case(),
-                rust_type: rust_type.0,
+                name,
+                rust_type: rust_type.0.clone(),
+                attributes: big_array_attribute(&rust_type.0),
+                constant: constant.map(|bytes| Discriminator(bytes.clone())),
             });
         }
 
         let mut accounts = Vec::new();
         for account in &instruction.accounts {
+            let default = resolve_account_default(program, instruction, account);
             accounts.push(AccountMetaData {
                 name: account.name.to_snake_case(),
                 is_mut: account.is_writable,
-                is_signer: match account.is_signer {
-                    SignerType::Boolean(is_signer) => is_signer,
-                    SignerType::Either(_) => false,
-                },
+                is_signer: matches!(account.is_signer, SignerType::Boolean(true)),
+                is_optional_signer: matches!(&account.is_signer, SignerType::Either(either) if either == "either"),
                 is_optional: account.is_optional,
-                address: None,
-                pda: None,
+                address: default.address,
+                is_program_id: default.is_program_id,
+                pda: default.pda,
+                default_value: default.value,
             });
         }
 
         instructions_data.push(InstructionData {
             struct_name,
             module_name,
-            discriminator,
+            discriminator: Discriminator(prefix),
             args,
             accounts,
             param_types: HashSet::with_capacity(0),
             requires_imports,
+            omit_optional_accounts: instruction.optional_account_strategy.as_deref()
+                == Some("omitted"),
         });
     }
 
-    instructions_data
+    Ok(instructions_data)
+}
+
+/// How an instruction account resolves from its `defaultValue`.
+#[derive(Default)]
+struct AccountDefault {
+    address: Option<String>,
+    is_program_id: bool,
+    pda: Option<Pda>,
+    value: Option<String>,
+}
+
+fn resolve_account_default(
+    program: &ProgramNode,
+    instruction: &InstructionNode,
+    account: &InstructionAccountNode,
+) -> AccountDefault {
+    match &account.default_value {
+        Some(ValueNode::PublicKeyValueNode { public_key }) => AccountDefault {
+            address: Some(public_key.clone()),
+            is_program_id: *public_key == program.public_key,
+            ..Default::default()
+        },
+        Some(ValueNode::ProgramIdValueNode) => AccountDefault {
+            address: Some(program.public_key.clone()),
+            is_program_id: true,
+            ..Default::default()
+        },
+        Some(ValueNode::PdaValueNode { pda, seeds }) => AccountDefault {
+            pda: resolve_pda(program, instruction, pda, seeds),
+            ..Default::default()
+        },
+        Some(ValueNode::AccountValueNode { name }) if is_account_param(instruction, name) => {
+            AccountDefault {
+                value: Some(name.to_snake_case()),
+                ..Default::default()
+            }
+        }
+        Some(ValueNode::ArgumentValueNode { name }) => AccountDefault {
+            value: Some(format!("self.{}", name.to_snake_case())),
+            ..Default::default()
+        },
+        _ => AccountDefault::default(),
+    }
+}
+
+/// The account exists and its pubkey is passed by the caller.
+fn is_account_param(instruction: &InstructionNode, name: &str) -> bool {
+    instruction
+        .accounts
+        .iter()
+        .any(|account| account.name == name && account.default_value.is_none())
+}
+
+fn resolve_pda(
+    program: &ProgramNode,
+    instruction: &InstructionNode,
+    pda: &PdaLink,
+    seed_values: &[PdaSeedValueNode],
+) -> Option<Pda> {
+    let pda_node = match pda {
+        PdaLink::PdaLinkNode { name } => program.pdas.iter().find(|pda| pda.name == *name)?,
+        PdaLink::PdaNode(pda_node) => pda_node,
+    };
+
+    let mut seeds = Vec::with_capacity(pda_node.seeds.len());
+    for seed in &pda_node.seeds {
+        let seed = match seed {
+            PdaSeedNode::ConstantPdaSeedNode { seed_type, value } => {
+                constant_pda_seed(seed_type, value)
+            }
+            PdaSeedNode::VariablePdaSeedNode { name, seed_type } => {
+                // Unbound seeds default to the account or argument of the same name.
+                let value = seed_values
+                    .iter()
+                    .find(|seed_value| seed_value.name == *name)
+                    .map(|seed_value| seed_value.value.clone())
+                    .or_else(|| {
+                        if instruction.accounts.iter().any(|a| a.name == *name) {
+                            Some(ValueNode::AccountValueNode { name: name.clone() })
+                        } else if instruction.arguments.iter().any(|a| a.name == *name) {
+                            Some(ValueNode::ArgumentValueNode { name: name.clone() })
+                        } else {
+                            None
+                        }
+                    });
+                match value {
+                    Some(ValueNode::AccountValueNode { name })
+                        if is_account_param(instruction, &name) =>
+                    {
+                        Some(PdaSeed {
+                            kind: "account".to_string(),
+                            value: None,
+                            path: Some(name.to_snake_case()),
+                            account: None,
+                            param_type: None,
+                            expr: None,
+                        })
+                    }
+                    Some(ValueNode::ArgumentValueNode { name }) => Some(PdaSeed {
+                        kind: "arg".to_string(),
+                        value: None,
+                        path: None,
+                        account: None,
+                        param_type: None,
+                        expr: Some(seed_bytes_expr(
+                            &format!("self.{}", name.to_snake_case()),
+                            seed_type,
+                        )),
+                    }),
+                    Some(value) => constant_pda_seed(seed_type, &value),
+                    None => None,
+                }
+            }
+        };
+        match seed {
+            Some(seed) => seeds.push(seed),
+            None => {
+                eprintln!(
+                    "Warning: Cannot resolve seeds of PDA `{}` in instruction `{}`",
+                    pda_node.name, instruction.name
+                );
+                return None;
+            }
+        }
+    }
+
+    Some(Pda {
+        seeds,
+        program: pda_node
+            .program_id
+            .clone()
+            .filter(|program_id| *program_id != program.public_key),
+    })
 }
 
 pub fn process_codama_defined_types(
     program: &ProgramNode,
     event_hints: &HashSet<String>,
-) -> Vec<TypeData> {
+) -> Result<Vec<TypeData>, NitrogenError> {
     let mut types_data = Vec::new();
+    for defined_type in &program.defined_types {
+        if event_hints.contains(&defined_type.name.to_upper_camel_case()) {
+            continue;
+        }
+        types_data.extend(process_codama_defined_type(defined_type)?);
+    }
+    Ok(types_data)
+}
+
+/// Event-hinted defined types, decoded by their discriminator.
+pub fn process_codama_events(
+    program: &ProgramNode,
+    event_hints: &HashSet<String>,
+) -> Result<Vec<EventData>, NitrogenError> {
+    let mut events_data = Vec::new();
 
     for defined_type in &program.defined_types {
-        let mut requires_imports = false;
-        let name = defined_type.name.to_upper_camel_case().clone();
-        let mut fields = Vec::new();
-        let mut kind = TypeKind::Struct;
-
-        match &defined_type.type_node {
-            TypeNode::StructTypeNode {
-                fields: struct_fields,
-            } => {
-                for field in struct_fields {
-                    let rust_type = map_type(&field.field_type);
-                    if rust_type.1 {
-                        requires_imports = true;
-                    }
-                    let is_pubkey = rust_type.0 == "Pubkey";
-                    fields.push(FieldData {
-                        name: field.name.to_snake_case(),
-                        rust_type: rust_type.0,
-                        is_pubkey,
-                        attributes: None,
-                    });
+        if !event_hints.contains(&defined_type.name.to_upper_camel_case()) {
+            continue;
+        }
+        let Some(mut type_data) = process_codama_defined_type(defined_type)? else {
+            continue;
+        };
+        if type_data.kind != TypeKind::Struct {
+            eprintln!(
+                "Warning: Event `{}` is not a struct and is skipped",
+                type_data.name
+            );
+            continue;
+        }
+        let discriminator = get_event_discriminator(defined_type, &type_data.name);
+        if type_data
+            .fields
+            .first()
+            .is_some_and(|field| field.name == "discriminator")
+        {
+            type_data.fields.remove(0);
+        }
+        events_data.push(EventData {
+            type_data,
+            discriminator: Discriminator(discriminator),
+        });
+    }
+
+    Ok(events_data)
+}
+
+pub fn process_codama_errors(program: &ProgramNode) -> Vec<ErrorData> {
+    program
+        .errors
+        .iter()
+        .map(|error| ErrorData {
+            name: error.name.to_upper_camel_case(),
+            code: error.code,
+            message: error.message.clone(),
+        })
+        .collect()
+}
+
+/// Defined types of other programs in `programs` linked from `program`, as
+/// `(program name, type name)`.
+pub fn process_codama_external_types(
+    program: &ProgramNode,
+    programs: &[&ProgramNode],
+) -> BTreeSet<(String, String)> {
+    let defines = |program: &ProgramNode, name: &str| {
+        program
+            .defined_types
+            .iter()
+            .any(|defined_type| defined_type.name == name)
+    };
+    let mut external_types = BTreeSet::new();
+    let mut visit = |name: &str, program_link: Option<&str>| {
+        let owner = match program_link {
+            Some(program_name) if program_name != program.name => {
+                programs.iter().find(|other| other.name == program_name)
+            }
+            _ if defines(program, name) => return,
+            _ => programs
+                .iter()
+                .find(|other| other.name != program.name && defines(other, name)),
+        };
+        match owner {
+            Some(owner) => {
+                external_types.insert((owner.name.clone(), name.to_string()));
+            }
+            None => eprintln!(
+                "Warning: Defined type `{}` linked from program `{}` was not found",
+                name, program.name
+            ),
+        }
+    };
+
+    for account in &program.accounts {
+        for field in &account.data.fields {
+            visit_defined_type_links(&field.field_type, &mut visit);
+        }
+    }
+    for instruction in &program.instructions {
+        for argument in &instruction.arguments {
+            visit_defined_type_links(&argument.arg_type, &mut visit);
+        }
+    }
+    for defined_type in &program.defined_types {
+        visit_defined_type_links(&defined_type.type_node, &mut visit);
+    }
+
+    external_types
+}
+
+/// Keeps the instructions and accounts of `program` selected by `filter` and
+/// `account_filter`, and the defined types still reachable from them or the
+/// event-hinted types.
+pub fn filter_codama_program(
+    program: &mut ProgramNode,
+    filter: &[String],
+    account_filter: &[String],
+    event_hints: &HashSet<String>,
+) {
+    if filter.is_empty() && account_filter.is_empty() {
+        return;
+    }
+    program
+        .instructions
+        .retain(|instruction| matches_filter(filter, &instruction.name));
+    program
+        .accounts
+        .retain(|account| matches_filter(account_filter, &account.name));
+
+    let mut pending: Vec<String> = program
+        .defined_types
+        .iter()
+        .filter(|defined_type| event_hints.contains(&defined_type.name.to_upper_camel_case()))
+        .map(|defined_type| defined_type.name.clone())
+        .collect();
+    let program_name = program.name.clone();
+    let mut visit = |name: &str, program_link: Option<&str>| {
+        if program_link.is_none_or(|link| link == program_name) {
+            pending.push(name.to_string());
+        }
+    };
+    for account in &program.accounts {
+        for field in &account.data.fields {
+            visit_defined_type_links(&field.field_type, &mut visit);
+        }
+    }
+    for instruction in &program.instructions {
+        for argument in &instruction.arguments {
+            visit_defined_type_links(&argument.arg_type, &mut visit);
+        }
+    }
+
+    let mut reachable = HashSet::new();
+    while let Some(name) = pending.pop() {
+        if !reachable.insert(name.clone()) {
+            continue;
+        }
+        if let Some(defined_type) = program
+            .defined_types
+            .iter()
+            .find(|defined_type| defined_type.name == name)
+        {
+            visit_defined_type_links(&defined_type.type_node, &mut |name, program_link| {
+                if program_link.is_none_or(|link| link == program_name) {
+                    pending.push(name.to_string());
                 }
+            });
+        }
+    }
+    program
+        .defined_types
+        .retain(|defined_type| reachable.contains(&defined_type.name));
+}
+
+/// Rust type of a defined type, or `None` for type nodes other than structs
+/// and enums.
+pub fn process_codama_defined_type(
+    defined_type: &DefinedTypeNode,
+) -> Result<Option<TypeData>, NitrogenError> {
+    let mut requires_imports = false;
+    let name = defined_type.name.to_upper_camel_case().clone();
+    let mut fields = Vec::new();
+    let mut kind = TypeKind::Struct;
+
+    match &defined_type.type_node {
+        TypeNode::StructTypeNode {
+            fields: struct_fields,
+        } => {
+            for field in struct_fields {
+                let field_name = field.name.to_snake_case();
+                let rust_type = map_type(&format!("{name}.{field_name}"), &field.field_type)?;
+                if rust_type.1 {
+                    requires_imports = true;
+                }
+                let is_pubkey = matches!(field.field_type, TypeNode::PublicKeyTypeNode);
+                fields.push(FieldData {
+                    name: field_name,
+                    rust_type: rust_type.0.clone(),
+                    is_pubkey,
+                    attributes: big_array_attribute(&rust_type.0),
+                });
             }
-            TypeNode::EnumTypeNode { variants, .. } => {
-                kind = TypeKind::Enum(
-                    variants
-                        .iter()
-                        .map(|variant| match variant {
-                            EnumVariantTypeNode::EnumEmptyVariantTypeNode { name } => {
-                                EnumVariantData {
-                                    name: name.to_upper_camel_case().clone(),
-                                    fields: None,
-                                }
-                            }
-                            EnumVariantTypeNode::EnumStructVariantTypeNode {
-                                name,
-                                struct_field,
-                            } => {
-                                let resolved_struct = resolve_struct_type(struct_field);
-                                let named_fields = match resolved_struct {
-                                    Some(struct_node) => struct_node
-                                        .fields
-                                        .iter()
-                                        .map(|field| {
-                                            let rust_type = map_type(&field.field_type);
-                                            if rust_type.1 {
-                                                requires_imports = true;
-                                            }
-                                            FieldData {
-                                                name: field.name.to_snake_case(),
-                                                rust_type: rust_type.0.clone(),
-                                                is_pubkey: rust_type.0 == "Pubkey",
-                                                attributes: None,
-                                            }
-                                        })
-                                        .collect(),
-                                    None => {
-                                        println!(
-                                            "Warning: Failed to resolve struct fields for enum \
-                                             variant `{}`",
-                                            name
-                                        );
-                                        Vec::new()
+        }
+        TypeNode::EnumTypeNode { variants, size } => {
+            if size.format != "u8" {
+                return Err(NitrogenError::unsupported(&name, "enum size"));
+            }
+            let mut enum_variants = Vec::new();
+            for variant in variants {
+                enum_variants.push(match variant {
+                    EnumVariantTypeNode::EnumEmptyVariantTypeNode { name } => EnumVariantData {
+                        name: name.to_upper_camel_case().clone(),
+                        fields: None,
+                    },
+                    EnumVariantTypeNode::EnumStructVariantTypeNode {
+                        name: variant_name,
+                        struct_field,
+                    } => {
+                        let variant_name = variant_name.to_upper_camel_case();
+                        let mut named_fields = Vec::new();
+                        match resolve_struct_type(struct_field) {
+                            Some(struct_node) => {
+                                for field in &struct_node.fields {
+                                    let field_name = field.name.to_snake_case();
+                                    let rust_type = map_type(
+                                        &format!("{name}::{variant_name}.{field_name}"),
+                                        &field.field_type,
+                                    )?;
+                                    if rust_type.1 {
+                                        requires_imports = true;
                                     }
-                                };
-
-                                EnumVariantData {
-                                    name: name.to_upper_camel_case().clone(),
-                                    fields: Some(EnumVariantFields::Named(named_fields)),
+                                    named_fields.push(FieldData {
+                                        name: field_name,
+                                        rust_type: rust_type.0.clone(),
+                                        is_pubkey: matches!(
+                                            field.field_type,
+                                            TypeNode::PublicKeyTypeNode
+                                        ),
+                                        attributes: big_array_attribute(&rust_type.0),
+                                    });
                                 }
                             }
-                            EnumVariantTypeNode::EnumTupleVariantTypeNode { name, tuple } => {
-                                let unnamed_fields = tuple
-                                    .items
-                                    .iter()
-                                    .map(|item| {
-                                        let rust_type = map_type(item);
-                                        if rust_type.1 {
-                                            requires_imports = true;
-                                        }
-                                        rust_type.0.clone()
-                                    })
-                                    .collect();
-                                EnumVariantData {
-                                    name: name.to_upper_camel_case().clone(),
-                                    fields: Some(EnumVariantFields::Unnamed(unnamed_fields)),
-                                }
+                            None => eprintln!(
+                                "Warning: Failed to resolve struct fields for enum variant `{}`",
+                                variant_name
+                            ),
+                        }
+
+                        EnumVariantData {
+                            name: variant_name,
+                            fields: Some(EnumVariantFields::Named(named_fields)),
+                        }
+                    }
+                    EnumVariantTypeNode::EnumTupleVariantTypeNode {
+                        name: variant_name,
+                        tuple,
+                    } => {
+                        let variant_name = variant_name.to_upper_camel_case();
+                        let mut unnamed_fields = Vec::new();
+                        for item in &tuple.items {
+                            let rust_type = map_type(&format!("{name}::{variant_name}"), item)?;
+                            if rust_type.1 {
+                                requires_imports = true;
                             }
-                        })
-                        .collect(),
-                );
+                            unnamed_fields.push(FieldData {
+                                name: rust_type.0.to_snake_case(),
+                                is_pubkey: matches!(item, TypeNode::PublicKeyTypeNode),
+                                attributes: big_array_attribute(&rust_type.0),
+                                rust_type: rust_type.0,
+                            });
+                        }
+                        EnumVariantData {
+                            name: variant_name,
+                            fields: Some(EnumVariantFields::Unnamed(unnamed_fields)),
+                        }
+                    }
+                });
             }
-            _ => continue, // Skip unsupported type nodes for now.
+            kind = TypeKind::Enum(enum_variants);
         }
+        _ => return Ok(None), // Skip unsupported type nodes for now.
+    }
 
-        if !event_hints.contains(&name) {
-            types_data.push(TypeData {
-                name,
-                fields,
-                kind,
-                requires_imports,
+    Ok(Some(TypeData {
+        name,
+        fields,
+        kind,
+        requires_imports,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::handlers::codama::{
+            types::AccountNode,
+            utils::{parse_event_hints, read_codama_idl},
+        },
+    };
+
+    fn counter_idl() -> ProgramNode {
+        read_codama_idl(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../idls/codama_counter.json"
+        ))
+        .unwrap()
+        .program
+    }
+
+    #[test]
+    fn test_codama_counter() {
+        let program = counter_idl();
+        assert_eq!(
+            program.public_key,
+            "CounterProgram11111111111111111111111111111"
+        );
+
+        let accounts = process_codama_accounts(&program).unwrap();
+        assert_eq!(accounts[0].discriminator.0, vec![1]);
+        assert_eq!(accounts[0].fields.len(), 3);
+
+        let instructions = process_codama_instructions(&program).unwrap();
+        assert_eq!(instructions[0].discriminator.0, vec![0]);
+        assert_eq!(instructions[1].discriminator.0, vec![1]);
+        assert_eq!(instructions[1].args[0].name, "amount");
+        assert_eq!(instructions[0].accounts[3].name, "system_program");
+    }
+
+    #[test]
+    fn test_codama_account_defaults() {
+        let program = counter_idl();
+        let instructions = process_codama_instructions(&program).unwrap();
+
+        let initialize = &instructions[0].accounts;
+        let counter = initialize[0].pda.as_ref().unwrap();
+        assert_eq!(counter.seeds[0].value.as_ref().unwrap().value, b"counter");
+        assert_eq!(counter.seeds[1].path.as_deref(), Some("authority"));
+        assert!(initialize[1].is_param());
+        assert_eq!(initialize[2].default_value.as_deref(), Some("authority"));
+        assert_eq!(
+            initialize[3].address.as_deref(),
+            Some("11111111111111111111111111111111")
+        );
+
+        let increment = &instructions[1].accounts;
+        let receipt = increment[2].pda.as_ref().unwrap();
+        assert_eq!(
+            receipt.seeds[2].expr.as_deref(),
+            Some("&self.amount.to_le_bytes()")
+        );
+        assert!(increment[3].is_program_id);
+        assert!(increment[4].is_optional_signer);
+        assert!(!increment[4].is_signer);
+        assert_eq!(increment[4].param_type(), "(Pubkey, bool)");
+        assert_eq!(increment[5].param_type(), "Option<(Pubkey, bool)>");
+        assert!(!instructions[1].omit_optional_accounts);
+
+        let mut program = program;
+        program.instructions[1].optional_account_strategy = Some("omitted".to_string());
+        assert!(process_codama_instructions(&program).unwrap()[1].omit_optional_accounts);
+    }
+
+    #[test]
+    fn test_codama_discriminators() {
+        let program = counter_idl();
+        let accounts = process_codama_accounts(&program).unwrap();
+        let receipt = &accounts[1];
+        assert_eq!(receipt.discriminator.0, vec![2]);
+        assert_eq!(receipt.discriminator_offset, 8);
+        assert_eq!(receipt.size, Some(9));
+        assert_eq!(receipt.fields.len(), 2);
+        assert_eq!(accounts[2].discriminator.0, vec![7, 7, 7, 7]);
+
+        let close = &process_codama_instructions(&program).unwrap()[2];
+        assert_eq!(close.discriminator.0, vec![0xca, 0xfe]);
+        assert!(close.args[0].constant.is_none());
+        assert_eq!(close.args[1].constant.as_ref().unwrap().0, b"v1");
+    }
+
+    #[test]
+    fn test_codama_constant_discriminators() {
+        let constant = |offset: usize, number: u64| {
+            serde_json::json!({
+                "kind": "constantDiscriminatorNode",
+                "offset": offset,
+                "constant": {
+                    "kind": "constantValueNode",
+                    "type": { "kind": "numberTypeNode", "format": "u16", "endian": "be" },
+                    "value": { "kind": "numberValueNode", "number": number },
+                },
+            })
+        };
+        let field = |name: &str, offset: usize| serde_json::json!({ "kind": "fieldDiscriminatorNode", "name": name, "offset": offset });
+        let number = |name: &str, format: &str, default: Option<u64>| {
+            let mut field = serde_json::json!({
+                "name": name,
+                "type": { "kind": "numberTypeNode", "format": format, "endian": "le" },
+            });
+            if let Some(number) = default {
+                field["defaultValue"] =
+                    serde_json::json!({ "kind": "numberValueNode", "number": number });
+            }
+            field
+        };
+        let instruction = |discriminators: serde_json::Value| -> InstructionNode {
+            serde_json::from_value(serde_json::json!({
+                "name": "swap",
+                "accounts": [],
+                "arguments": [number("version", "u8", Some(9)), number("amount", "u64", None)],
+                "discriminators": discriminators,
+            }))
+            .unwrap()
+        };
+        let offset_error = |discriminators: serde_json::Value| match get_instruction_discriminator(
+            &instruction(discriminators),
+        ) {
+            Err(NitrogenError::Unsupported { construct, .. }) => construct,
+            result => panic!("expected an unsupported discriminator, got {result:?}"),
+        };
+
+        let swap = instruction(serde_json::json!([constant(0, 1), constant(2, 0x0203)]));
+        let discriminator = get_instruction_discriminator(&swap).unwrap();
+        assert_eq!(discriminator.prefix, vec![0, 1, 2, 3]);
+
+        // A constant can follow a leading discriminator argument.
+        let swap = instruction(serde_json::json!([
+            constant(1, 0x0203),
+            field("version", 0)
+        ]));
+        let discriminator = get_instruction_discriminator(&swap).unwrap();
+        assert_eq!(discriminator.prefix, vec![9, 2, 3]);
+        assert!(discriminator.prefix_args.contains("version"));
+
+        let swap = instruction(serde_json::json!([constant(0, 1), field("version", 2)]));
+        let discriminator = get_instruction_discriminator(&swap).unwrap();
+        assert_eq!(discriminator.prefix, vec![0, 1, 9]);
+
+        assert_eq!(
+            offset_error(serde_json::json!([constant(4, 1)])),
+            "discriminator at offset 4"
+        );
+        assert_eq!(
+            offset_error(serde_json::json!([field("version", 1)])),
+            "discriminator at offset 1"
+        );
+        assert_eq!(
+            offset_error(serde_json::json!([field("amount", 0)])),
+            "discriminator"
+        );
+    }
+
+    #[test]
+    fn test_codama_account_discriminators() {
+        let account = |discriminators: serde_json::Value| -> AccountNode {
+            serde_json::from_value(serde_json::json!({
+                "name": "pool",
+                "data": {
+                    "kind": "structTypeNode",
+                    "fields": [
+                        {
+                            "name": "key",
+                            "type": { "kind": "numberTypeNode", "format": "u8", "endian": "le" },
+                            "defaultValue": { "kind": "numberValueNode", "number": 5 },
+                        },
+                        { "name": "owner", "type": { "kind": "publicKeyTypeNode" } },
+                    ],
+                },
+                "discriminators": discriminators,
+            }))
+            .unwrap()
+        };
+        let constant = |offset: usize| {
+            serde_json::json!({
+                "kind": "constantDiscriminatorNode",
+                "offset": offset,
+                "constant": {
+                    "kind": "constantValueNode",
+                    "type": { "kind": "bytesTypeNode" },
+                    "value": { "kind": "bytesValueNode", "encoding": "base16", "data": "abcd" },
+                },
+            })
+        };
+        let key = |offset: usize| serde_json::json!({ "kind": "fieldDiscriminatorNode", "name": "key", "offset": offset });
+
+        let pool = account(serde_json::json!([constant(1), key(0)]));
+        let discriminator = get_account_discriminator(&pool, &pool.name).unwrap();
+        assert_eq!(discriminator.bytes, vec![5, 0xab, 0xcd]);
+        assert_eq!(discriminator.offset, 0);
+        assert_eq!(discriminator.prefix_fields, vec!["key"]);
+
+        let pool = account(serde_json::json!([key(40), constant(41)]));
+        let discriminator = get_account_discriminator(&pool, &pool.name).unwrap();
+        assert_eq!(discriminator.bytes, vec![5, 0xab, 0xcd]);
+        assert_eq!(discriminator.offset, 40);
+        assert!(discriminator.prefix_fields.is_empty());
+
+        for discriminators in [
+            serde_json::json!([key(0), constant(4)]),
+            serde_json::json!([key(0), constant(0)]),
+        ] {
+            let pool = account(discriminators);
+            assert!(matches!(
+                get_account_discriminator(&pool, &pool.name),
+                Err(NitrogenError::Unsupported { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_codama_unsupported_type() {
+        let mut program = counter_idl();
+        program.instructions[1].arguments[1].arg_type =
+            serde_json::from_value(serde_json::json!({ "kind": "structTypeNode", "fields": [] }))
+                .unwrap();
+        let Err(NitrogenError::Unsupported { item, construct }) =
+            process_codama_instructions(&program)
+        else {
+            panic!("expected an unsupported type node");
+        };
+        assert_eq!(item, "increment.amount");
+        assert_eq!(construct, "type node");
+    }
+
+    #[test]
+    fn test_codama_layouts() {
+        let types = process_codama_defined_types(&counter_idl(), &HashSet::new()).unwrap();
+        let label = types.iter().find(|t| t.name == "CounterLabel").unwrap();
+        let rust_types: Vec<&str> = label.fields.iter().map(|f| f.rust_type.as_str()).collect();
+        let codec = |t: &str| format!("nitrogen_instruction_builder::codec::{t}");
+        assert_eq!(rust_types[0], codec("BigEndian<u32>"));
+        assert_eq!(rust_types[1], codec("PrefixedString<u8>"));
+        assert_eq!(rust_types[2], codec("FixedString<8>"));
+        assert_eq!(rust_types[3], "[u8; 4]");
+        assert_eq!(rust_types[4], codec("Padded<4, u16>"));
+        assert_eq!(rust_types[5], codec("PrefixedVec<u16, u8>"));
+        assert_eq!(rust_types[9], codec("ShortU16"));
+        assert_eq!(
+            rust_types[10],
+            codec("PrefixedVec<nitrogen_instruction_builder::codec::ShortU16, u8>")
+        );
+        assert_eq!(
+            rust_types[11],
+            codec("PrefixedString<nitrogen_instruction_builder::codec::BigEndian<u32>>")
+        );
+        assert_eq!(rust_types[12], codec("RemainderVec<u8>"));
+
+        let incremented = types
+            .iter()
+            .find(|t| t.name == "CounterIncremented")
+            .unwrap();
+        assert!(incremented.fields[0].is_pubkey);
+        assert!(!incremented.fields[1].is_pubkey);
+    }
+
+    #[test]
+    fn test_codama_unsupported_layouts() {
+        let number = |format: &str| serde_json::json!({ "kind": "numberTypeNode", "format": format, "endian": "le" });
+        let construct = |type_node: serde_json::Value| {
+            let mut program = counter_idl();
+            let label = program
+                .defined_types
+                .iter_mut()
+                .find(|t| t.name == "counterLabel")
+                .unwrap();
+            let TypeNode::StructTypeNode { fields } = &mut label.type_node else {
+                panic!("expected a struct");
+            };
+            fields[0].field_type = serde_json::from_value(type_node).unwrap();
+            match process_codama_defined_types(&program, &HashSet::new()) {
+                Err(NitrogenError::Unsupported { item, construct }) => {
+                    assert_eq!(item, "CounterLabel.id");
+                    construct
+                }
+                result => panic!("expected an unsupported layout, got {result:?}"),
+            }
+        };
+
+        let size_prefix = serde_json::json!({
+            "kind": "sizePrefixTypeNode",
+            "type": number("u64"),
+            "prefix": number("u32"),
+        });
+        assert_eq!(construct(size_prefix), "size prefix");
+
+        let constant = serde_json::json!({
+            "kind": "constantValueNode",
+            "type": number("u8"),
+            "value": { "kind": "numberValueNode", "number": 1 },
+        });
+        let hidden_prefix = serde_json::json!({
+            "kind": "hiddenPrefixTypeNode",
+            "type": number("u64"),
+            "prefix": [constant],
+        });
+        assert_eq!(construct(hidden_prefix), "hidden prefix");
+
+        let zeroable = serde_json::json!({
+            "kind": "zeroableOptionTypeNode",
+            "item": number("u8"),
+            "zeroValue": constant,
+        });
+        assert_eq!(construct(zeroable), "zero value");
+
+        for kind in ["preOffsetTypeNode", "postOffsetTypeNode"] {
+            let offset = serde_json::json!({
+                "kind": kind,
+                "offset": 2,
+                "strategy": "relative",
+                "type": number("u8"),
             });
+            assert_eq!(construct(offset), "offset");
         }
+
+        let boolean = serde_json::json!({ "kind": "booleanTypeNode", "size": number("u32") });
+        assert_eq!(construct(boolean), "boolean size");
+    }
+
+    #[test]
+    fn test_codama_unsupported_enum_size() {
+        let mut program = counter_idl();
+        let mode = program
+            .defined_types
+            .iter_mut()
+            .find(|t| t.name == "counterMode")
+            .unwrap();
+        let TypeNode::EnumTypeNode { size, .. } = &mut mode.type_node else {
+            panic!("expected an enum");
+        };
+        size.format = "u32".to_string();
+        let Err(NitrogenError::Unsupported { item, construct }) =
+            process_codama_defined_types(&program, &HashSet::new())
+        else {
+            panic!("expected an unsupported enum size");
+        };
+        assert_eq!(item, "CounterMode");
+        assert_eq!(construct, "enum size");
     }
 
-    types_data
+    #[test]
+    fn test_codama_errors_and_events() {
+        let program = counter_idl();
+        let errors = process_codama_errors(&program);
+        assert_eq!(errors[1].name, "Overflow");
+        assert_eq!(
+            errors[1].message_literal(),
+            r#""Counter \"value\" overflowed""#
+        );
+
+        let hints = parse_event_hints(Some("counterIncremented,counter_closed".to_string()));
+        let types = process_codama_defined_types(&program, &hints).unwrap();
+        assert!(!types.iter().any(|t| t.name == "CounterIncremented"));
+
+        let events = process_codama_events(&program, &hints).unwrap();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].discriminator.0.len(), 8);
+        assert_eq!(events[1].discriminator.0, vec![0xc1, 0x05]);
+        assert_eq!(events[1].type_data.fields.len(), 1);
+    }
+
+    #[test]
+    fn test_codama_external_types() {
+        let root = read_codama_idl(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../idls/codama_multi.json"
+        ))
+        .unwrap();
+        let programs: Vec<&ProgramNode> = std::iter::once(&root.program)
+            .chain(&root.additional_programs)
+            .collect();
+
+        let external_types = process_codama_external_types(&root.program, &programs);
+        assert_eq!(external_types.into_iter().collect::<Vec<_>>(), vec![
+            ("splToken".to_string(), "accountState".to_string()),
+            ("splToken".to_string(), "extensionType".to_string()),
+        ]);
+        assert!(process_codama_external_types(programs[1], &programs).is_empty());
+    }
+
+    #[test]
+    fn test_filter_codama_program() {
+        let mut program = counter_idl();
+        let event_hints = parse_event_hints(Some("CounterIncremented".to_string()));
+        filter_codama_program(&mut program, &["increment".to_string()], &[], &event_hints);
+
+        let instructions = process_codama_instructions(&program).unwrap();
+        assert_eq!(instructions.len(), 1);
+        assert_eq!(instructions[0].module_name, "increment");
+
+        let mut type_names: Vec<_> = program
+            .defined_types
+            .iter()
+            .map(|defined_type| defined_type.name.as_str())
+            .collect();
+        type_names.sort();
+        assert_eq!(type_names, vec![
+            "counterConfig",
+            "counterIncremented",
+            "counterMode"
+        ]);
+        assert_eq!(process_codama_accounts(&program).unwrap().len(), 3);
+    }
 }
@@ -4,15 +4,61 @@ use serde::{Deserialize, Serialize};
 #[serde(rename_all = "camelCase")]
 pub struct RootNode {
     pub program: ProgramNode,
+    #[serde(default)]
+    pub additional_programs: Vec<ProgramNode>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ProgramNode {
     pub name: String,
+    pub public_key: String,
     pub accounts: Vec<AccountNode>,
     pub instructions: Vec<InstructionNode>,
     pub defined_types: Vec<DefinedTypeNode>,
+    #[serde(default)]
+    pub pdas: Vec<PdaNode>,
+    #[serde(default)]
+    pub errors: Vec<ErrorNode>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ProgramLinkNode {
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorNode {
+    pub name: String,
+    pub code: u32,
+    pub message: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PdaNode {
+    pub name: String,
+    pub seeds: Vec<PdaSeedNode>,
+    #[serde(default)]
+    pub program_id: Option<String>,
+}
+
+#[allow(clippy::enum_variant_names)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase", tag = "kind")]
+pub enum PdaSeedNode {
+    ConstantPdaSeedNode {
+        #[serde(rename = "type")]
+        seed_type: TypeNode,
+        value: ValueNode,
+    },
+    VariablePdaSeedNode {
+        name: String,
+        #[serde(rename = "type")]
+        seed_type: TypeNode,
+    },
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -20,6 +66,8 @@ pub struct ProgramNode {
 pub struct AccountNode {
     pub name: String,
     pub data: StructTypeNode,
+    #[serde(default)]
+    pub discriminators: Vec<DiscriminatorNode>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -28,6 +76,10 @@ pub struct InstructionNode {
     pub name: String,
     pub accounts: Vec<InstructionAccountNode>,
     pub arguments: Vec<InstructionArgumentNode>,
+    #[serde(default)]
+    pub discriminators: Vec<DiscriminatorNode>,
+    /// `"programId"` or `"omitted"`, how optional accounts left out are passed.
+    pub optional_account_strategy: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -80,6 +132,8 @@ pub enum TypeNode {
     },
     DefinedTypeLinkNode {
         name: String,
+        #[serde(default)]
+        program: Option<ProgramLinkNode>,
     },
     BytesTypeNode,
     SizePrefixTypeNode {
@@ -124,6 +178,7 @@ pub enum TypeNode {
     },
     ZeroableOptionTypeNode {
         item: Box<TypeNode>,
+        #[serde(rename = "zeroValue")]
         zero_value: Option<ConstantValueNode>,
     },
     MapTypeNode {
@@ -146,11 +201,55 @@ pub enum CountNode {
 #[derive(Debug, Deserialize, Serialize, Clone)]
 #[serde(rename_all = "camelCase", tag = "kind")]
 pub enum ValueNode {
-    BytesValueNode { data: String, encoding: String },
-    NumberValueNode { number: u64 },
+    BytesValueNode {
+        data: String,
+        encoding: String,
+    },
+    NumberValueNode {
+        number: u64,
+    },
     NoneValueNode,
     IdentityValueNode,
-    BooleanValueNode { boolean: bool },
+    BooleanValueNode {
+        boolean: bool,
+    },
+    StringValueNode {
+        string: String,
+    },
+    PublicKeyValueNode {
+        #[serde(rename = "publicKey")]
+        public_key: String,
+    },
+    ProgramIdValueNode,
+    AccountValueNode {
+        name: String,
+    },
+    ArgumentValueNode {
+        name: String,
+    },
+    PdaValueNode {
+        pda: PdaLink,
+        #[serde(default)]
+        seeds: Vec<PdaSeedValueNode>,
+    },
+    /// Value nodes the generator doesn't resolve, e.g. `payerValueNode`.
+    #[serde(other)]
+    Unsupported,
+}
+
+/// The PDA of a `pdaValueNode`, either linked by name or inlined.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase", tag = "kind")]
+pub enum PdaLink {
+    PdaLinkNode { name: String },
+    PdaNode(PdaNode),
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PdaSeedValueNode {
+    pub name: String,
+    pub value: ValueNode,
 }
 
 #[allow(clippy::enum_variant_names)]
@@ -191,12 +290,23 @@ pub struct TupleTypeNode {
     pub items: Vec<TypeNode>,
 }
 
-#[allow(dead_code)]
-#[derive(Debug, Deserialize, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct FieldDiscriminatorNode {
-    pub name: String,
-    pub offset: usize,
+#[allow(clippy::enum_variant_names)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase", tag = "kind")]
+pub enum DiscriminatorNode {
+    ConstantDiscriminatorNode {
+        constant: ConstantValueNode,
+        #[serde(default)]
+        offset: usize,
+    },
+    FieldDiscriminatorNode {
+        name: String,
+        #[serde(default)]
+        offset: usize,
+    },
+    SizeDiscriminatorNode {
+        size: usize,
+    },
 }
 
 #[allow(dead_code)]
@@ -213,6 +323,8 @@ pub struct InstructionAccountNode {
     pub is_writable: bool,
     pub is_signer: SignerType,
     pub is_optional: bool,
+    #[serde(default)]
+    pub default_value: Option<ValueNode>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
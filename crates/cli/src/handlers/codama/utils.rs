@@ -2,26 +2,39 @@ use {
     super::types::{
         AccountNode,
         CountNode,
-        InstructionArgumentNode,
+        DefinedTypeNode,
+        DiscriminatorNode,
+        EnumVariantTypeNode,
+        InstructionNode,
         RootNode,
         StructTypeNode,
         TypeNode,
     },
-    crate::handlers::codama::types::ValueNode,
+    crate::{
+        error::NitrogenError,
+        handlers::codama::types::ValueNode,
+        instructions::{PdaSeed, SeedData},
+        util::read_idl_json,
+    },
     anyhow::Result,
+    base64::Engine,
     heck::ToUpperCamelCase,
     sha2::{Digest, Sha256},
-    std::{collections::HashSet, fs::File},
+    std::collections::{HashMap, HashSet},
 };
 
-pub fn map_type(type_node: &TypeNode) -> (String, bool) {
-    match type_node {
+const CODEC: &str = "nitrogen_instruction_builder::codec";
+
+/// Rust type of a type node, and whether it refers to the crate's types.
+/// `field` names the field or argument of the type in errors.
+pub fn map_type(field: &str, type_node: &TypeNode) -> Result<(String, bool), NitrogenError> {
+    Ok(match type_node {
         TypeNode::AmountTypeNode {
             decimals,
             unit,
             number,
         } => {
-            let (rust_type, requires_import) = map_type(number);
+            let (rust_type, requires_import) = map_type(field, number)?;
             let unit_info = match unit {
                 Some(u) => format!(" (unit: {})", u),
                 None => "".to_string(),
@@ -35,186 +48,567 @@ pub fn map_type(type_node: &TypeNode) -> (String, bool) {
             (rust_type, requires_import)
         }
 
-        TypeNode::NumberTypeNode { format, .. } => (format.to_string(), false),
+        TypeNode::NumberTypeNode { format, endian } => (number_type(format, endian), false),
         TypeNode::PublicKeyTypeNode => ("solana_pubkey::Pubkey".to_string(), false),
-        TypeNode::BooleanTypeNode { .. } => ("bool".to_string(), false),
-        TypeNode::FixedSizeTypeNode { size, r#type } => {
-            let (rust_type, requires_import) = map_type(r#type);
-            (format!("[{}; {}]", rust_type, size), requires_import)
-        }
-        TypeNode::OptionTypeNode { item, .. } => {
-            let (rust_type, requires_import) = map_type(item);
-            (format!("Option<{}>", rust_type), requires_import)
-        }
-        TypeNode::DefinedTypeLinkNode { name } => (name.to_upper_camel_case().clone(), true),
-        TypeNode::BytesTypeNode => ("u8".to_string(), false),
-        TypeNode::StringTypeNode { .. } => ("String".to_string(), false),
-        TypeNode::SolAmountTypeNode { number } => {
-            let inner = map_type(number);
-            (inner.0.to_string(), inner.1)
+        TypeNode::BooleanTypeNode { size } => {
+            if size.format != "u8" {
+                return Err(NitrogenError::unsupported(field, "boolean size"));
+            }
+            ("bool".to_string(), false)
+        }
+        TypeNode::FixedSizeTypeNode { size, r#type } => match r#type.as_ref() {
+            TypeNode::StringTypeNode { .. } => (format!("{CODEC}::FixedString<{size}>"), false),
+            TypeNode::BytesTypeNode => (format!("[u8; {}]", size), false),
+            inner_type => {
+                let (rust_type, requires_import) = map_type(field, inner_type)?;
+                (
+                    format!("{CODEC}::Padded<{size}, {rust_type}>"),
+                    requires_import,
+                )
+            }
+        },
+        TypeNode::OptionTypeNode { item, prefix } => {
+            let (rust_type, requires_import) = map_type(field, item)?;
+            match number_type(&prefix.format, &prefix.endian).as_str() {
+                "u8" => (format!("Option<{}>", rust_type), requires_import),
+                format => (
+                    format!("{CODEC}::PrefixedOption<{format}, {rust_type}>"),
+                    requires_import,
+                ),
+            }
         }
-        TypeNode::SizePrefixTypeNode { r#type, .. } => {
-            let inner = map_type(r#type);
+        TypeNode::DefinedTypeLinkNode { name, .. } => (name.to_upper_camel_case().clone(), true),
+        TypeNode::BytesTypeNode => (format!("{CODEC}::RemainderVec<u8>"), false),
+        TypeNode::StringTypeNode { encoding } => {
+            warn_string_encoding(encoding);
+            (format!("{CODEC}::RemainderString"), false)
+        }
+        TypeNode::SolAmountTypeNode { number } => {
+            let inner = map_type(field, number)?;
             (inner.0.to_string(), inner.1)
         }
-        TypeNode::ArrayTypeNode { item, count } => {
-            let (rust_type, requires_import) = map_type(item);
-
-            match count {
-                CountNode::FixedCountNode { value } => {
-                    (format!("[{}; {}]", rust_type, value), requires_import)
+        TypeNode::SizePrefixTypeNode { r#type, prefix } => {
+            let format = number_type(&prefix.format, &prefix.endian);
+            match (r#type.as_ref(), format.as_str()) {
+                (TypeNode::StringTypeNode { encoding }, "u32") => {
+                    warn_string_encoding(encoding);
+                    ("String".to_string(), false)
+                }
+                (TypeNode::StringTypeNode { encoding }, format) => {
+                    warn_string_encoding(encoding);
+                    (format!("{CODEC}::PrefixedString<{format}>"), false)
                 }
-                CountNode::PrefixedCountNode { .. } | CountNode::RemainderCountNode => {
-                    (format!("Vec<{}>", rust_type), requires_import)
+                (TypeNode::BytesTypeNode, "u32") => ("Vec<u8>".to_string(), false),
+                (TypeNode::BytesTypeNode, format) => {
+                    (format!("{CODEC}::PrefixedVec<{format}, u8>"), false)
                 }
+                _ => return Err(NitrogenError::unsupported(field, "size prefix")),
             }
         }
+        TypeNode::ArrayTypeNode { item, count } => {
+            let (rust_type, requires_import) = map_type(field, item)?;
+            (map_count(rust_type, count), requires_import)
+        }
         TypeNode::RemainderOptionTypeNode { item } => {
-            let (rust_type, requires_import) = map_type(item);
-            (format!("Option<Vec<{}>>", rust_type), requires_import)
+            let (rust_type, requires_import) = map_type(field, item)?;
+            (
+                format!("{CODEC}::RemainderOption<{}>", rust_type),
+                requires_import,
+            )
         }
-        TypeNode::HiddenPrefixTypeNode { r#type, .. } => {
-            let (rust_type, requires_import) = map_type(r#type);
-            (rust_type.to_string(), requires_import)
+        TypeNode::HiddenPrefixTypeNode { .. } => {
+            return Err(NitrogenError::unsupported(field, "hidden prefix"));
         }
         TypeNode::PreOffsetTypeNode {
             offset,
             strategy,
             inner_type,
         } => {
-            let (rust_type, requires_import) = map_type(inner_type);
-            println!(
-                "Warning: PreOffsetTypeNode detected (offset: {}, strategy: {}). Inner type: {}",
-                offset, strategy, rust_type
-            );
-            (rust_type, requires_import)
+            if strategy != "padded" {
+                return Err(NitrogenError::unsupported(field, "offset"));
+            }
+            let (rust_type, requires_import) = map_type(field, inner_type)?;
+            (
+                format!("{CODEC}::PrePadded<{offset}, {rust_type}>"),
+                requires_import,
+            )
         }
         TypeNode::PostOffsetTypeNode {
             offset,
             strategy,
             inner_type,
         } => {
-            let (rust_type, requires_import) = map_type(inner_type);
-            println!(
-                "Warning: PostOffsetTypeNode detected (offset: {}, strategy: {}). Inner type: {}",
-                offset, strategy, rust_type
-            );
-            (rust_type, requires_import)
+            if strategy != "padded" {
+                return Err(NitrogenError::unsupported(field, "offset"));
+            }
+            let (rust_type, requires_import) = map_type(field, inner_type)?;
+            (
+                format!("{CODEC}::PostPadded<{offset}, {rust_type}>"),
+                requires_import,
+            )
         }
         TypeNode::ZeroableOptionTypeNode { item, zero_value } => {
-            let (rust_type, requires_import) = map_type(item);
             if zero_value.is_some() {
-                println!(
-                    "Warning: `ZeroableOptionTypeNode` with `zero_value` detected. Custom \
-                     deserialization logic may be required."
-                );
+                return Err(NitrogenError::unsupported(field, "zero value"));
             }
-            (format!("Option<{}>", rust_type), requires_import)
+            let (rust_type, requires_import) = map_type(field, item)?;
+            (
+                format!("{CODEC}::ZeroableOption<{}>", rust_type),
+                requires_import,
+            )
         }
         TypeNode::MapTypeNode { key, value, count } => {
-            let (key_type, key_requires_import) = map_type(key);
-            let (value_type, value_requires_import) = map_type(value);
+            let (key_type, key_requires_import) = map_type(field, key)?;
+            let (value_type, value_requires_import) = map_type(field, value)?;
             let requires_import = key_requires_import || value_requires_import;
 
-            let rust_type = match count {
-                CountNode::FixedCountNode { value } => {
-                    format!("[({}, {}); {}]", key_type, value_type, value)
-                }
-                CountNode::PrefixedCountNode { .. } | CountNode::RemainderCountNode => {
-                    format!("Vec<({}, {})>", key_type, value_type)
-                }
-            };
-
-            (rust_type, requires_import)
+            (
+                map_count(format!("({}, {})", key_type, value_type), count),
+                requires_import,
+            )
         }
-        _ => ("UnsupportedType".to_string(), false),
-    }
+        _ => return Err(NitrogenError::unsupported(field, "type node")),
+    })
 }
 
-pub fn get_instruction_discriminator(
-    ix_arguments: &[InstructionArgumentNode],
-    instruction_name: &str,
-) -> Vec<u8> {
-    if let Some(first_argument) = ix_arguments.first() {
-        if first_argument.name == "discriminator" {
-            if let Some(default_value) = &first_argument.default_value {
-                match default_value {
-                    #[allow(unused_variables)]
-                    ValueNode::BytesValueNode { data, encoding } if encoding == "base16" => {
-                        todo!()
+/// Calls `visit` with the name and program of every `DefinedTypeLinkNode`
+/// in `type_node`.
+pub fn visit_defined_type_links(type_node: &TypeNode, visit: &mut impl FnMut(&str, Option<&str>)) {
+    match type_node {
+        TypeNode::DefinedTypeLinkNode { name, program } => {
+            visit(name, program.as_ref().map(|program| program.name.as_str()))
+        }
+        TypeNode::AmountTypeNode { number: inner, .. }
+        | TypeNode::SolAmountTypeNode { number: inner }
+        | TypeNode::FixedSizeTypeNode { r#type: inner, .. }
+        | TypeNode::SizePrefixTypeNode { r#type: inner, .. }
+        | TypeNode::HiddenPrefixTypeNode { r#type: inner, .. }
+        | TypeNode::PreOffsetTypeNode {
+            inner_type: inner, ..
+        }
+        | TypeNode::PostOffsetTypeNode {
+            inner_type: inner, ..
+        }
+        | TypeNode::OptionTypeNode { item: inner, .. }
+        | TypeNode::RemainderOptionTypeNode { item: inner }
+        | TypeNode::ZeroableOptionTypeNode { item: inner, .. }
+        | TypeNode::ArrayTypeNode { item: inner, .. } => visit_defined_type_links(inner, visit),
+        TypeNode::MapTypeNode { key, value, .. } => {
+            visit_defined_type_links(key, visit);
+            visit_defined_type_links(value, visit);
+        }
+        TypeNode::StructTypeNode { fields } => {
+            for field in fields {
+                visit_defined_type_links(&field.field_type, visit);
+            }
+        }
+        TypeNode::EnumTypeNode { variants, .. } => {
+            for variant in variants {
+                match variant {
+                    EnumVariantTypeNode::EnumEmptyVariantTypeNode { .. } => {}
+                    EnumVariantTypeNode::EnumStructVariantTypeNode { struct_field, .. } => {
+                        visit_defined_type_links(struct_field, visit)
                     }
-                    ValueNode::NumberValueNode { number } => {
-                        if let TypeNode::NumberTypeNode { format, .. } = &first_argument.arg_type {
-                            let bytes = match format.as_str() {
-                                "u8" => vec![*number as u8],
-                                "u16" => (*number as u16).to_le_bytes().to_vec(),
-                                "u32" => (*number as u32).to_le_bytes().to_vec(),
-                                "u64" => number.to_le_bytes().to_vec(),
-                                _ => [0u8; 8].to_vec(),
-                            };
-
-                            return bytes;
+                    EnumVariantTypeNode::EnumTupleVariantTypeNode { tuple, .. } => {
+                        for item in &tuple.items {
+                            visit_defined_type_links(item, visit);
                         }
                     }
-                    _ => {}
                 }
             }
         }
+        _ => {}
     }
+}
 
-    let mut hasher = Sha256::new();
-    let discriminator_input = format!("global:{}", instruction_name);
-    hasher.update(discriminator_input.as_bytes());
-    let hash = hasher.finalize();
-    hash[..8].to_vec()
+/// Collection of `item_type` sized by `count`.
+fn map_count(item_type: String, count: &CountNode) -> String {
+    match count {
+        CountNode::FixedCountNode { value } => format!("[{}; {}]", item_type, value),
+        CountNode::PrefixedCountNode { prefix } => match prefix_format(prefix).as_str() {
+            "u32" => format!("Vec<{}>", item_type),
+            format => format!("{CODEC}::PrefixedVec<{format}, {item_type}>"),
+        },
+        CountNode::RemainderCountNode => format!("{CODEC}::RemainderVec<{}>", item_type),
+    }
+}
+
+/// Number format of a length prefix.
+fn prefix_format(prefix: &TypeNode) -> String {
+    match prefix {
+        TypeNode::NumberTypeNode { format, endian } => number_type(format, endian),
+        _ => {
+            eprintln!("Warning: Unsupported length prefix {:?}, using u32", prefix);
+            "u32".to_string()
+        }
+    }
+}
+
+/// Rust type of a number, also usable as a length prefix.
+fn number_type(format: &str, endian: &str) -> String {
+    match (format, endian) {
+        ("shortU16", _) => format!("{CODEC}::ShortU16"),
+        ("u8" | "i8", _) | (_, "le") => format.to_string(),
+        _ => format!("{CODEC}::BigEndian<{format}>"),
+    }
+}
+
+fn warn_string_encoding(encoding: &str) {
+    if encoding != "utf8" {
+        eprintln!(
+            "Warning: `StringTypeNode` with {} encoding is serialized as utf8",
+            encoding
+        );
+    }
+}
+
+/// Little-endian bytes of `number` encoded as the `format` number type.
+pub fn number_le_bytes(format: &str, number: u64) -> Option<Vec<u8>> {
+    Some(match format {
+        "u8" | "i8" => vec![number as u8],
+        "u16" | "i16" => (number as u16).to_le_bytes().to_vec(),
+        "u32" | "i32" => (number as u32).to_le_bytes().to_vec(),
+        "u64" | "i64" => number.to_le_bytes().to_vec(),
+        "u128" | "i128" => (number as u128).to_le_bytes().to_vec(),
+        _ => return None,
+    })
+}
+
+/// Decodes the data of a `bytesValueNode`.
+pub fn decode_bytes_value(data: &str, encoding: &str) -> Option<Vec<u8>> {
+    match encoding {
+        "base16" => hex::decode(data).ok(),
+        "base58" => bs58::decode(data).into_vec().ok(),
+        "base64" => base64::engine::general_purpose::STANDARD.decode(data).ok(),
+        "utf8" => Some(data.as_bytes().to_vec()),
+        _ => None,
+    }
+}
+
+/// Bytes of the constant `value` of type `type_node`.
+pub fn constant_value_bytes(type_node: &TypeNode, value: &ValueNode) -> Option<Vec<u8>> {
+    match (type_node, value) {
+        (TypeNode::StringTypeNode { encoding }, ValueNode::StringValueNode { string }) => {
+            decode_bytes_value(string, encoding)
+        }
+        (_, ValueNode::StringValueNode { string }) => Some(string.as_bytes().to_vec()),
+        (_, ValueNode::BytesValueNode { data, encoding }) => decode_bytes_value(data, encoding),
+        (TypeNode::NumberTypeNode { format, endian }, ValueNode::NumberValueNode { number }) => {
+            let mut bytes = number_le_bytes(format, *number)?;
+            if endian == "be" {
+                bytes.reverse();
+            }
+            Some(bytes)
+        }
+        (_, ValueNode::BooleanValueNode { boolean }) => Some(vec![*boolean as u8]),
+        (_, ValueNode::PublicKeyValueNode { public_key }) => {
+            bs58::decode(public_key).into_vec().ok()
+        }
+        _ => None,
+    }
+}
+
+/// Returns a PDA seed for a constant value.
+pub fn constant_pda_seed(seed_type: &TypeNode, value: &ValueNode) -> Option<PdaSeed> {
+    let bytes = match value {
+        ValueNode::PublicKeyValueNode { public_key } => {
+            return Some(expr_pda_seed(format!(
+                "solana_pubkey::pubkey!(\"{public_key}\").as_ref()"
+            )));
+        }
+        ValueNode::ProgramIdValueNode => {
+            return Some(expr_pda_seed("program_id.as_ref()".to_string()));
+        }
+        _ => constant_value_bytes(seed_type, value)?,
+    };
+    Some(PdaSeed {
+        kind: "const".to_string(),
+        value: Some(SeedData { value: bytes }),
+        path: None,
+        account: None,
+        param_type: None,
+        expr: None,
+    })
 }
 
-pub fn get_account_discriminator(account_node: &AccountNode, account_name: &str) -> String {
+fn expr_pda_seed(expr: String) -> PdaSeed {
+    PdaSeed {
+        kind: "const".to_string(),
+        value: None,
+        path: None,
+        account: None,
+        param_type: None,
+        expr: Some(expr),
+    }
+}
+
+/// Returns the expression borrowing the seed bytes of `value`, a Rust
+/// expression of the seed's type.
+pub fn seed_bytes_expr(value: &str, seed_type: &TypeNode) -> String {
+    match seed_type {
+        TypeNode::NumberTypeNode { endian, .. } if endian == "be" => {
+            format!("&{value}.to_be_bytes()")
+        }
+        TypeNode::NumberTypeNode { .. } => format!("&{value}.to_le_bytes()"),
+        TypeNode::StringTypeNode { .. } => format!("{value}.as_bytes()"),
+        TypeNode::SizePrefixTypeNode { r#type, .. }
+        | TypeNode::FixedSizeTypeNode { r#type, .. }
+            if matches!(r#type.as_ref(), TypeNode::StringTypeNode { .. }) =>
+        {
+            format!("{value}.as_bytes()")
+        }
+        _ => format!("{value}.as_ref()"),
+    }
+}
+
+/// How a Codama instruction's discriminators are serialized.
+#[derive(Debug, Default)]
+pub struct InstructionDiscriminator {
+    /// Bytes written before the arguments.
+    pub prefix: Vec<u8>,
+    /// Leading arguments serialized in the prefix.
+    pub prefix_args: HashSet<String>,
+    /// Arguments serialized as fixed bytes.
+    pub constant_args: HashMap<String, Vec<u8>>,
+}
+
+/// Bytes of a discriminator, at `offset` in the data, held by `field` unless
+/// it's a constant.
+struct DiscriminatorBytes<'a> {
+    offset: usize,
+    bytes: Vec<u8>,
+    field: Option<&'a String>,
+}
+
+/// Bytes of the constant and field discriminators of `item` ordered by offset,
+/// and the data size required by a size discriminator. `field_bytes` returns
+/// the constant value of a field.
+fn discriminator_bytes<'a>(
+    item: &str,
+    nodes: &'a [DiscriminatorNode],
+    field_bytes: impl Fn(&str) -> Option<Vec<u8>>,
+) -> Result<(Vec<DiscriminatorBytes<'a>>, Option<usize>), NitrogenError> {
+    let mut discriminators = Vec::new();
+    let mut size = None;
+    for node in nodes {
+        let (bytes, offset, field) = match node {
+            DiscriminatorNode::SizeDiscriminatorNode { size: data_size } => {
+                size = Some(*data_size);
+                continue;
+            }
+            DiscriminatorNode::ConstantDiscriminatorNode { constant, offset } => (
+                constant_value_bytes(&constant.r#type, &constant.value),
+                *offset,
+                None,
+            ),
+            DiscriminatorNode::FieldDiscriminatorNode { name, offset } => {
+                (field_bytes(name), *offset, Some(name))
+            }
+        };
+        let bytes = bytes.ok_or_else(|| NitrogenError::unsupported(item, "discriminator"))?;
+        discriminators.push(DiscriminatorBytes {
+            offset,
+            bytes,
+            field,
+        });
+    }
+    discriminators.sort_by_key(|discriminator| discriminator.offset);
+    Ok((discriminators, size))
+}
+
+fn discriminator_offset_error(item: &str, offset: usize) -> NitrogenError {
+    NitrogenError::unsupported(item, &format!("discriminator at offset {offset}"))
+}
+
+pub fn get_instruction_discriminator(
+    instruction: &InstructionNode,
+) -> Result<InstructionDiscriminator, NitrogenError> {
+    let mut discriminator = InstructionDiscriminator::default();
+
+    if !instruction.discriminators.is_empty() {
+        let item = &instruction.name;
+        let (discriminators, _) = discriminator_bytes(item, &instruction.discriminators, |name| {
+            let arg = instruction.arguments.iter().find(|arg| arg.name == name)?;
+            constant_value_bytes(&arg.arg_type, arg.default_value.as_ref()?)
+        })?;
+        let mut discriminators = discriminators.into_iter().peekable();
+        let mut arguments = instruction.arguments.iter().peekable();
+
+        // Constants are written before the arguments, so they can only follow
+        // other constants or leading discriminator arguments.
+        let mut position = 0;
+        while let Some(next) = discriminators.next_if(|next| {
+            next.offset == position
+                && next.field.is_none_or(|name| {
+                    arguments
+                        .next_if(|argument| &argument.name == name)
+                        .is_some()
+                })
+        }) {
+            if let Some(name) = next.field {
+                discriminator.prefix_args.insert(name.clone());
+            }
+            position += next.bytes.len();
+            discriminator.prefix.extend(next.bytes);
+        }
+
+        // Other discriminator arguments must sit at their offset once the
+        // arguments before them are serialized.
+        let mut position = Some(position);
+        for argument in arguments {
+            let size = match discriminators.next_if(|next| next.field == Some(&argument.name)) {
+                Some(next) if position == Some(next.offset) => {
+                    let size = next.bytes.len();
+                    discriminator
+                        .constant_args
+                        .insert(argument.name.clone(), next.bytes);
+                    Some(size)
+                }
+                Some(next) => return Err(discriminator_offset_error(item, next.offset)),
+                None => fixed_size(&argument.arg_type),
+            };
+            position = position.zip(size).map(|(position, size)| position + size);
+        }
+        if let Some(next) = discriminators.next() {
+            return Err(discriminator_offset_error(item, next.offset));
+        }
+        return Ok(discriminator);
+    }
+
+    if let Some(first_argument) = instruction.arguments.first() {
+        if first_argument.name == "discriminator" {
+            discriminator.prefix = first_argument
+                .default_value
+                .as_ref()
+                .and_then(|value| constant_value_bytes(&first_argument.arg_type, value))
+                .unwrap_or_else(|| anchor_discriminator("global", &instruction.name));
+            discriminator
+                .prefix_args
+                .insert(first_argument.name.clone());
+            return Ok(discriminator);
+        }
+    }
+
+    discriminator.prefix = anchor_discriminator("global", &instruction.name);
+    Ok(discriminator)
+}
+
+/// Serialized size of `type_node` when it doesn't depend on the value.
+fn fixed_size(type_node: &TypeNode) -> Option<usize> {
+    match type_node {
+        TypeNode::NumberTypeNode { format, .. } => number_size(format),
+        TypeNode::BooleanTypeNode { size } => number_size(&size.format),
+        TypeNode::PublicKeyTypeNode => Some(32),
+        TypeNode::AmountTypeNode { number, .. } | TypeNode::SolAmountTypeNode { number } => {
+            fixed_size(number)
+        }
+        TypeNode::FixedSizeTypeNode { size, .. } => Some(*size),
+        TypeNode::ArrayTypeNode {
+            item,
+            count: CountNode::FixedCountNode { value },
+        } => Some(fixed_size(item)? * value),
+        _ => None,
+    }
+}
+
+fn number_size(format: &str) -> Option<usize> {
+    match format {
+        "u8" | "i8" => Some(1),
+        "u16" | "i16" => Some(2),
+        "u32" | "i32" | "f32" => Some(4),
+        "u64" | "i64" | "f64" => Some(8),
+        "u128" | "i128" => Some(16),
+        _ => None,
+    }
+}
+
+/// How a Codama account is identified.
+#[derive(Debug, Default)]
+pub struct AccountDiscriminator {
+    pub bytes: Vec<u8>,
+    pub offset: usize,
+    pub size: Option<usize>,
+    /// Fields holding the discriminator prefix, left out of the account struct.
+    pub prefix_fields: Vec<String>,
+}
+
+/// Discriminators of an account are checked as one run of bytes, so they must
+/// follow each other.
+pub fn get_account_discriminator(
+    account_node: &AccountNode,
+    account_name: &str,
+) -> Result<AccountDiscriminator, NitrogenError> {
+    let mut discriminator = AccountDiscriminator::default();
+    let field_bytes = |name: &str| {
+        let field = account_node.data.fields.iter().find(|f| f.name == name)?;
+        constant_value_bytes(&field.field_type, field.default_value.as_ref()?)
+    };
+
+    if !account_node.discriminators.is_empty() {
+        let item = &account_name.to_upper_camel_case();
+        let (discriminators, size) =
+            discriminator_bytes(item, &account_node.discriminators, field_bytes)?;
+        discriminator.size = size;
+        discriminator.offset = discriminators.first().map_or(0, |first| first.offset);
+
+        // A prefix is skipped before decoding, along with the leading fields
+        // holding it.
+        let mut fields = account_node.data.fields.iter();
+        for next in discriminators {
+            if next.offset != discriminator.offset + discriminator.bytes.len() {
+                return Err(discriminator_offset_error(item, next.offset));
+            }
+            if let Some(name) = next.field
+                && discriminator.offset == 0
+            {
+                if fields.next().is_none_or(|field| &field.name != name) {
+                    return Err(discriminator_offset_error(item, next.offset));
+                }
+                discriminator.prefix_fields.push(name.clone());
+            }
+            discriminator.bytes.extend(next.bytes);
+        }
+        return Ok(discriminator);
+    }
+
     if let Some(first_data_field) = account_node.data.fields.first() {
         if first_data_field.name == "discriminator" {
-            if let Some(default_value) = &first_data_field.default_value {
-                match default_value {
-                    ValueNode::BytesValueNode { data, encoding } if encoding == "base16" => {
-                        return format!("0x{}", data);
-                    }
-                    ValueNode::NumberValueNode { number } => {
-                        if let TypeNode::NumberTypeNode { format, .. } =
-                            &first_data_field.field_type
-                        {
-                            let bytes = match format.as_str() {
-                                "u8" => vec![*number as u8],
-                                "u16" => (*number as u16).to_le_bytes().to_vec(),
-                                "u32" => (*number as u32).to_le_bytes().to_vec(),
-                                "u64" => number.to_le_bytes().to_vec(),
-                                _ => [0u8; 8].to_vec(),
-                            };
-
-                            return format!("0x{}", hex::encode(&bytes));
-                        }
-                    }
-                    _ => {}
-                }
+            discriminator.bytes = field_bytes(&first_data_field.name)
+                .unwrap_or_else(|| anchor_discriminator("account", account_name));
+            discriminator.prefix_fields = vec![first_data_field.name.clone()];
+            return Ok(discriminator);
+        }
+    }
+
+    discriminator.bytes = anchor_discriminator("account", account_name);
+    Ok(discriminator)
+}
+
+/// Bytes of a leading constant `discriminator` field, or Anchor's
+/// `event:<Name>` hash.
+pub fn get_event_discriminator(defined_type: &DefinedTypeNode, event_name: &str) -> Vec<u8> {
+    if let TypeNode::StructTypeNode { fields } = &defined_type.type_node {
+        if let Some(field) = fields.first().filter(|field| field.name == "discriminator") {
+            if let Some(bytes) = field
+                .default_value
+                .as_ref()
+                .and_then(|value| constant_value_bytes(&field.field_type, value))
+            {
+                return bytes;
             }
         }
     }
+    anchor_discriminator("event", event_name)
+}
 
+fn anchor_discriminator(namespace: &str, name: &str) -> Vec<u8> {
     let mut hasher = Sha256::new();
-    let discriminator_input = format!("account:{}", account_name);
-    hasher.update(discriminator_input.as_bytes());
-    let hash = hasher.finalize();
-    let discriminator_bytes = &hash[..8];
-    format!("0x{}", hex::encode(discriminator_bytes))
+    hasher.update(format!("{namespace}:{name}").as_bytes());
+    hasher.finalize()[..8].to_vec()
 }
 
 pub fn read_codama_idl(idl_path: &str) -> Result<RootNode> {
-    let file = File::open(idl_path).expect("Failed to open file");
-    match serde_json::from_reader(file) {
-        Ok(idl) => Ok(idl),
-        Err(e) => {
-            println!("Error parsing Codama IDL: {:?}", e);
-            anyhow::bail!("Error parsing  Codama idl: {:?}", e);
-        }
-    }
+    Ok(read_idl_json(idl_path)?)
 }
 
 pub fn parse_event_hints(hints: Option<String>) -> HashSet<String> {
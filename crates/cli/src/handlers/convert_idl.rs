@@ -0,0 +1,320 @@
+use {
+    crate::{
+        accounts::legacy_compute_account_discriminator,
+        error::NitrogenError,
+        idl::{
+            Idl,
+            IdlAccount,
+            IdlEnumField,
+            IdlEnumVariant,
+            IdlError,
+            IdlEvent,
+            IdlInstruction,
+            IdlInstructionAccount,
+            IdlInstructionArg,
+            IdlMetadata,
+            IdlTypeDefinition,
+            IdlTypeDefinitionField,
+            IdlTypeDefinitionTy,
+        },
+        instructions::legacy_compute_instruction_discriminator,
+        legacy_idl::{
+            IdlDefinedType,
+            LegacyIdl,
+            LegacyIdlEnumField,
+            LegacyIdlEnumFields,
+            LegacyIdlEnumVariant,
+            LegacyIdlType,
+            LegacyIdlTypeDefinitionField,
+        },
+        util::legacy_read_idl,
+    },
+    anyhow::{Result, bail},
+    heck::ToSnakeCase,
+    sha2::{Digest, Sha256},
+    std::fs,
+};
+
+/// Spec version written into converted IDLs.
+const IDL_SPEC: &str = "0.1.0";
+
+/// Converts a legacy Anchor IDL to the 0.30 spec, writing it to `output` or
+/// to stdout.
+pub fn convert_idl(path: String, output: Option<String>, address: Option<String>) -> Result<()> {
+    let legacy_idl = legacy_read_idl(&path)?;
+    let idl = convert_legacy_idl(legacy_idl, address)?;
+    let json = serde_json::to_string_pretty(&idl)?;
+
+    match output {
+        Some(output) => {
+            fs::write(&output, json + "\n").map_err(NitrogenError::io("write", &output))?;
+            println!("Converted {path} to {output}");
+        }
+        None => println!("{json}"),
+    }
+
+    Ok(())
+}
+
+/// Converts `legacy_idl`, computing the discriminators the legacy spec leaves
+/// implicit. `address` overrides `metadata.address`.
+pub fn convert_legacy_idl(legacy_idl: LegacyIdl, address: Option<String>) -> Result<Idl> {
+    let Some(address) = address.or(legacy_idl.metadata.and_then(|metadata| metadata.address))
+    else {
+        bail!("Legacy IDL has no `metadata.address`, pass the program address explicitly");
+    };
+
+    let instructions = legacy_idl
+        .instructions
+        .into_iter()
+        .map(|instruction| {
+            Ok(IdlInstruction {
+                discriminator: legacy_compute_instruction_discriminator(
+                    &instruction.name.to_snake_case(),
+                    instruction.discriminant.as_ref(),
+                )?,
+                name: instruction.name.to_snake_case(),
+                docs: instruction.docs,
+                accounts: instruction
+                    .accounts
+                    .into_iter()
+                    .map(|account| IdlInstructionAccount {
+                        name: account.name.to_snake_case(),
+                        writable: account.is_mut.then_some(true),
+                        signer: account.is_signer.then_some(true),
+                        optional: account.is_optional.filter(|optional| *optional),
+                        pda: None,
+                        address: None,
+                        desc: account.desc,
+                        docs: account.docs,
+                    })
+                    .collect(),
+                args: instruction
+                    .args
+                    .into_iter()
+                    .map(|arg| IdlInstructionArg {
+                        name: arg.name.to_snake_case(),
+                        type_: convert_type(arg.type_),
+                    })
+                    .collect(),
+            })
+        })
+        .collect::<Result<_>>()?;
+
+    let mut accounts = Vec::new();
+    let mut types = Vec::new();
+    for account in legacy_idl.accounts {
+        accounts.push(IdlAccount {
+            name: account.name.clone(),
+            discriminator: legacy_compute_account_discriminator(&account.name).0,
+        });
+        types.push(IdlTypeDefinition {
+            name: account.name,
+            type_: IdlTypeDefinitionTy {
+                kind: account.type_.kind,
+                fields: account
+                    .type_
+                    .fields
+                    .map(|fields| fields.into_iter().map(convert_named_field).collect()),
+                variants: account.type_.variants.map(convert_variants),
+            },
+        });
+    }
+    for type_def in legacy_idl.types {
+        types.push(IdlTypeDefinition {
+            name: type_def.name,
+            type_: IdlTypeDefinitionTy {
+                kind: type_def.type_.kind,
+                fields: type_def.type_.fields.map(|fields| {
+                    fields
+                        .into_iter()
+                        .map(|field| match field {
+                            LegacyIdlEnumField::Named(field) => convert_named_field(field),
+                            LegacyIdlEnumField::Tuple(type_) => {
+                                IdlEnumField::Tuple(convert_type(type_))
+                            }
+                        })
+                        .collect()
+                }),
+                variants: type_def.type_.variants.map(convert_variants),
+            },
+        });
+    }
+
+    let mut events = Vec::new();
+    for event in legacy_idl.events {
+        events.push(IdlEvent {
+            name: event.name.clone(),
+            discriminator: Sha256::digest(format!("event:{}", event.name))[..8].to_vec(),
+        });
+        types.push(IdlTypeDefinition {
+            name: event.name,
+            type_: IdlTypeDefinitionTy {
+                kind: "struct".to_string(),
+                fields: Some(
+                    event
+                        .fields
+                        .into_iter()
+                        .map(|field| {
+                            IdlEnumField::Named(IdlTypeDefinitionField {
+                                name: field.name.to_snake_case(),
+                                type_: convert_type(field.type_),
+                            })
+                        })
+                        .collect(),
+                ),
+                variants: None,
+            },
+        });
+    }
+
+    let errors = legacy_idl
+        .errors
+        .into_iter()
+        .map(|error| IdlError {
+            code: error.code as u32,
+            name: error.name,
+            msg: error.msg,
+        })
+        .collect();
+
+    Ok(Idl {
+        address,
+        metadata: IdlMetadata {
+            name: legacy_idl.name.to_snake_case(),
+            version: legacy_idl.version,
+            spec: IDL_SPEC.to_string(),
+            description: String::new(),
+        },
+        instructions,
+        accounts,
+        errors,
+        types,
+        events,
+    })
+}
+
+/// Maps `publicKey` to `pubkey` and `defined: "X"` to `defined: { name: "X" }`.
+fn convert_type(type_: LegacyIdlType) -> LegacyIdlType {
+    let convert = |type_: Box<LegacyIdlType>| Box::new(convert_type(*type_));
+    match type_ {
+        LegacyIdlType::Primitive(primitive) if primitive == "publicKey" => {
+            LegacyIdlType::Primitive("pubkey".to_string())
+        }
+        LegacyIdlType::OptionPrimitive { option } if option == "publicKey" => {
+            LegacyIdlType::OptionPrimitive {
+                option: "pubkey".to_string(),
+            }
+        }
+        LegacyIdlType::Defined { defined } => LegacyIdlType::DefinedWithName {
+            defined: IdlDefinedType { name: defined },
+        },
+        LegacyIdlType::Array { array: (item, len) } => LegacyIdlType::Array {
+            array: (convert(item), len),
+        },
+        LegacyIdlType::Vec { vec } => LegacyIdlType::Vec { vec: convert(vec) },
+        LegacyIdlType::Option { option } => LegacyIdlType::Option {
+            option: convert(option),
+        },
+        LegacyIdlType::Tuple { tuple } => LegacyIdlType::Tuple {
+            tuple: tuple.into_iter().map(convert_type).collect(),
+        },
+        LegacyIdlType::HashMap {
+            hash_map: (key, value),
+        } => LegacyIdlType::HashMap {
+            hash_map: (convert(key), convert(value)),
+        },
+        type_ => type_,
+    }
+}
+
+fn convert_named_field(field: LegacyIdlTypeDefinitionField) -> IdlEnumField {
+    IdlEnumField::Named(IdlTypeDefinitionField {
+        name: field.name.to_snake_case(),
+        type_: convert_type(field.type_),
+    })
+}
+
+fn convert_variants(variants: Vec<LegacyIdlEnumVariant>) -> Vec<IdlEnumVariant> {
+    variants
+        .into_iter()
+        .map(|variant| IdlEnumVariant {
+            name: variant.name,
+            fields: variant.fields.map(|fields| match fields {
+                LegacyIdlEnumFields::Named(fields) => LegacyIdlEnumFields::Named(
+                    fields
+                        .into_iter()
+                        .map(|field| LegacyIdlTypeDefinitionField {
+                            name: field.name.to_snake_case(),
+                            type_: convert_type(field.type_),
+                            docs: field.docs,
+                        })
+                        .collect(),
+                ),
+                LegacyIdlEnumFields::Tuple(types) => {
+                    LegacyIdlEnumFields::Tuple(types.into_iter().map(convert_type).collect())
+                }
+            }),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            accounts::{legacy_process_accounts, process_accounts},
+            instructions::{legacy_process_instructions, process_instructions},
+            types::{legacy_process_types, process_types},
+        },
+    };
+
+    const LEGACY_ESCROW: &str =
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../../idls/legacy_escrow.json");
+
+    #[test]
+    fn test_convert_round_trip() {
+        let idl = convert_legacy_idl(legacy_read_idl(LEGACY_ESCROW).unwrap(), None).unwrap();
+        let json = serde_json::to_string(&idl).unwrap();
+        let idl: Idl = serde_json::from_str(&json).unwrap();
+        assert_eq!(serde_json::to_string(&idl).unwrap(), json);
+
+        let legacy_idl = legacy_read_idl(LEGACY_ESCROW).unwrap();
+        assert_eq!(
+            format!("{:?}", process_instructions(&idl, &[])),
+            format!(
+                "{:?}",
+                legacy_process_instructions(&legacy_idl, &[]).unwrap()
+            )
+        );
+        assert_eq!(
+            format!("{:?}", process_accounts(&idl, &[])),
+            format!("{:?}", legacy_process_accounts(&legacy_idl, &[]))
+        );
+        let legacy_types = legacy_process_types(&legacy_idl, None);
+        assert_eq!(
+            format!(
+                "{:?}",
+                &process_types(&idl, None).unwrap()[..legacy_types.len()]
+            ),
+            format!("{legacy_types:?}")
+        );
+
+        assert_eq!(idl.events[0].discriminator, vec![
+            98, 241, 195, 122, 213, 0, 162, 161
+        ]);
+        assert_eq!(idl.errors[1].msg, None);
+    }
+
+    #[test]
+    fn test_convert_requires_address() {
+        let mut legacy_idl = legacy_read_idl(LEGACY_ESCROW).unwrap();
+        legacy_idl.metadata = None;
+        assert!(convert_legacy_idl(legacy_idl, None).is_err());
+
+        let legacy_idl = legacy_read_idl(LEGACY_ESCROW).unwrap();
+        let idl = convert_legacy_idl(legacy_idl, Some("Other".to_string())).unwrap();
+        assert_eq!(idl.address, "Other");
+    }
+}
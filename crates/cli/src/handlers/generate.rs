@@ -0,0 +1,129 @@
+use {
+    crate::{
+        commands::{IdlSource, IdlStandard, ParseOptions},
+        config::{Overrides, read_config},
+        handlers::{self, FileChanges},
+        util::detect_idl_standard,
+    },
+    anyhow::{Result, anyhow, bail},
+    std::path::Path,
+};
+
+/// Generates the encoders of every program listed in the config at
+/// `config_path`, then summarizes the files touched.
+pub fn generate(config_path: String, check: bool) -> Result<()> {
+    let config = read_config(&config_path)?;
+    if config.programs.is_empty() {
+        bail!("{config_path} lists no `[[program]]`");
+    }
+    let base_dir = Path::new(&config_path)
+        .parent()
+        .unwrap_or_else(|| Path::new(""));
+
+    let mut changes = FileChanges::default();
+    for program in config.programs {
+        let (options, overrides) = program.into_parse_options(base_dir, check)?;
+        changes.extend(parse_idl(options, &overrides)?);
+    }
+
+    if !check {
+        println!();
+        for (label, filenames) in [
+            ("created", &changes.created),
+            ("changed", &changes.changed),
+            ("deleted", &changes.deleted),
+        ] {
+            for filename in filenames {
+                println!("{label:>8} {filename}");
+            }
+        }
+        println!(
+            "{} created, {} changed, {} deleted, {} unchanged",
+            changes.created.len(),
+            changes.changed.len(),
+            changes.deleted.len(),
+            changes.unchanged
+        );
+    }
+
+    Ok(())
+}
+
+/// Generates the encoder described by `options`, as `nitrogen parse` does.
+pub fn parse_idl(options: ParseOptions, overrides: &Overrides) -> Result<FileChanges> {
+    match options.idl {
+        IdlSource::FilePath(path) => {
+            let standard = match options.standard {
+                Some(standard) => standard,
+                None => {
+                    let standard = detect_idl_standard(&path)?;
+                    println!("Detected {standard} IDL");
+                    standard
+                }
+            };
+            match standard {
+                IdlStandard::Codama => {
+                    if options.filter_events.is_some() {
+                        bail!(
+                            "The '--filter-events' option can't be used with --codama, events are \
+                             selected with '--event-hints'."
+                        );
+                    }
+                    handlers::parse_codama(
+                        path,
+                        options.output,
+                        options.crate_name,
+                        options.filter,
+                        options.filter_accounts,
+                        options.event_hints,
+                        options.human_readable,
+                        options.check,
+                        overrides,
+                    )
+                }
+                IdlStandard::Anchor | IdlStandard::AnchorLegacy | IdlStandard::Shank => {
+                    if options.event_hints.is_some() {
+                        bail!("The '--event-hints' option can only be used with --codama.");
+                    }
+                    let parse = match standard {
+                        IdlStandard::AnchorLegacy => handlers::parse_legacy,
+                        IdlStandard::Shank => handlers::parse_shank,
+                        _ => handlers::parse,
+                    };
+                    parse(
+                        path,
+                        options.output,
+                        options.crate_name,
+                        options.filter,
+                        options.filter_accounts,
+                        options.filter_events,
+                        options.human_readable,
+                        options.check,
+                        overrides,
+                    )
+                }
+            }
+        }
+        IdlSource::ProgramAddress(program_address) => {
+            let url = options.url.as_ref().ok_or_else(|| {
+                anyhow!(
+                    "Network URL (--url / -u) argument is required when parsing an IDL from a \
+                     program address."
+                )
+            })?;
+
+            handlers::process_pda_idl(
+                program_address,
+                url,
+                options.output,
+                options.crate_name,
+                options.filter,
+                options.filter_accounts,
+                options.filter_events,
+                options.human_readable,
+                options.check,
+                overrides,
+            )
+        }
+    }
+}
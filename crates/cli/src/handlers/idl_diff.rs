@@ -0,0 +1,519 @@
+use {
+    crate::handlers::{
+        FieldReport,
+        InstructionAccountReport,
+        ProgramReport,
+        inspect_idl,
+        print_table,
+    },
+    anyhow::{Result, bail},
+    serde::Serialize,
+    std::collections::{BTreeMap, BTreeSet},
+};
+
+/// Changes between two versions of a program's IDL.
+#[derive(Debug, Serialize)]
+pub struct IdlDiff {
+    pub breaking: usize,
+    pub additive: usize,
+    pub changes: Vec<Change>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Change {
+    pub severity: Severity,
+    /// e.g. "instruction `deposit_for_burn`".
+    pub item: String,
+    pub change: String,
+}
+
+/// Whether encodings of the old IDL stop matching the new one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Breaking,
+    Additive,
+}
+
+/// Prints the changes from the IDL at `old_path` to the one at `new_path`,
+/// failing if any is breaking.
+pub fn idl_diff(old_path: String, new_path: String, json: bool) -> Result<()> {
+    let old = inspect_idl(&old_path, None)?;
+    let new = inspect_idl(&new_path, None)?;
+    let changes = diff_reports(&old, &new);
+    let count = |severity| {
+        changes
+            .iter()
+            .filter(|change| change.severity == severity)
+            .count()
+    };
+    let diff = IdlDiff {
+        breaking: count(Severity::Breaking),
+        additive: count(Severity::Additive),
+        changes,
+    };
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&diff)?);
+    } else {
+        for (title, severity) in [
+            ("Breaking changes", Severity::Breaking),
+            ("Additive changes", Severity::Additive),
+        ] {
+            print_table(
+                title,
+                &["ITEM", "CHANGE"],
+                diff.changes
+                    .iter()
+                    .filter(|change| change.severity == severity)
+                    .map(|change| vec![change.item.clone(), change.change.clone()])
+                    .collect(),
+            );
+        }
+    }
+
+    if diff.breaking > 0 {
+        bail!(
+            "{} breaking changes from {old_path} to {new_path}",
+            diff.breaking
+        );
+    }
+    Ok(())
+}
+
+/// Compares the wire formats of two versions of a program.
+pub fn diff_reports(old: &ProgramReport, new: &ProgramReport) -> Vec<Change> {
+    let mut changes = Changes::default();
+
+    if old.address != new.address {
+        changes.breaking(
+            "program".to_string(),
+            format!("address changed from {} to {}", old.address, new.address),
+        );
+    }
+
+    let instructions = by_name(&old.instructions, &new.instructions, |i| &i.name);
+    for (name, (old, new)) in instructions {
+        let item = format!("instruction `{name}`");
+        let (old, new) = match (old, new) {
+            (Some(old), Some(new)) => (old, new),
+            (Some(_), None) => {
+                changes.breaking(item, "removed".to_string());
+                continue;
+            }
+            (None, _) => {
+                changes.additive(item, "added".to_string());
+                continue;
+            }
+        };
+        changes.discriminator(&item, &old.discriminator, &new.discriminator);
+        changes.fields(&item, "argument", &old.args, &new.args, false);
+        changes.instruction_accounts(&item, &old.accounts, &new.accounts);
+    }
+
+    let pdas = by_name(&old.pdas, &new.pdas, |pda| {
+        format!("{}.{}", pda.instruction, pda.account)
+    });
+    for (name, (old, new)) in pdas {
+        let item = format!("PDA `{name}`");
+        match (old, new) {
+            (Some(old), Some(new)) => {
+                if old.seeds != new.seeds {
+                    changes.breaking(
+                        item.clone(),
+                        format!(
+                            "seeds changed from [{}] to [{}]",
+                            old.seeds.join(", "),
+                            new.seeds.join(", ")
+                        ),
+                    );
+                }
+                if old.program != new.program {
+                    changes.breaking(item, "derivation program changed".to_string());
+                }
+            }
+            (Some(_), None) => changes.breaking(item, "no longer derived".to_string()),
+            (None, _) => changes.additive(item, "derived from seeds".to_string()),
+        }
+    }
+
+    let accounts = by_name(&old.accounts, &new.accounts, |account| &account.name);
+    for (name, (old, new)) in accounts {
+        let item = format!("account `{name}`");
+        let (old, new) = match (old, new) {
+            (Some(old), Some(new)) => (old, new),
+            (Some(_), None) => {
+                changes.breaking(item, "removed".to_string());
+                continue;
+            }
+            (None, _) => {
+                changes.additive(item, "added".to_string());
+                continue;
+            }
+        };
+        changes.discriminator(&item, &old.discriminator, &new.discriminator);
+        changes.fields(&item, "field", &old.fields, &new.fields, false);
+    }
+
+    // 0.30 IDLs also list event structs as types, which legacy ones don't.
+    let event_names: BTreeSet<&String> = old
+        .events
+        .iter()
+        .chain(&new.events)
+        .map(|event| &event.name)
+        .collect();
+    let types = by_name(&old.types, &new.types, |type_report| &type_report.name);
+    for (name, (old, new)) in types {
+        if event_names.contains(name) {
+            continue;
+        }
+        let item = format!("type `{name}`");
+        let (old, new) = match (old, new) {
+            (Some(old), Some(new)) => (old, new),
+            (Some(_), None) => {
+                changes.breaking(item, "removed".to_string());
+                continue;
+            }
+            (None, _) => {
+                changes.additive(item, "added".to_string());
+                continue;
+            }
+        };
+        if old.kind != new.kind {
+            changes.breaking(
+                item,
+                format!("kind changed from {} to {}", old.kind, new.kind),
+            );
+        } else if old.kind == "enum" {
+            changes.fields(&item, "variant", &old.fields, &new.fields, true);
+        } else {
+            changes.fields(&item, "field", &old.fields, &new.fields, false);
+        }
+    }
+
+    let events = by_name(&old.events, &new.events, |event| &event.name);
+    for (name, (old, new)) in events {
+        let item = format!("event `{name}`");
+        let (old, new) = match (old, new) {
+            (Some(old), Some(new)) => (old, new),
+            (Some(_), None) => {
+                changes.breaking(item, "removed".to_string());
+                continue;
+            }
+            (None, _) => {
+                changes.additive(item, "added".to_string());
+                continue;
+            }
+        };
+        changes.discriminator(&item, &old.discriminator, &new.discriminator);
+        changes.fields(&item, "field", &old.fields, &new.fields, false);
+    }
+
+    let errors = by_name(&old.errors, &new.errors, |error| error.code);
+    for (code, (old, new)) in errors {
+        let item = format!("error {code}");
+        match (old, new) {
+            (Some(old), Some(new)) => {
+                if old.name != new.name {
+                    changes.breaking(
+                        item,
+                        format!("renamed from `{}` to `{}`", old.name, new.name),
+                    );
+                } else if old.message != new.message {
+                    changes.additive(item, "message changed".to_string());
+                }
+            }
+            (Some(old), None) => changes.breaking(item, format!("`{}` removed", old.name)),
+            (None, Some(new)) => changes.additive(item, format!("`{}` added", new.name)),
+            (None, None) => {}
+        }
+    }
+
+    changes.0
+}
+
+/// Pairs up the items of both versions by `key`.
+fn by_name<'a, T, K: Ord>(
+    old: &'a [T],
+    new: &'a [T],
+    key: impl Fn(&'a T) -> K,
+) -> BTreeMap<K, (Option<&'a T>, Option<&'a T>)> {
+    let mut items = BTreeMap::new();
+    for item in old {
+        items.entry(key(item)).or_insert((None, None)).0 = Some(item);
+    }
+    for item in new {
+        items.entry(key(item)).or_insert((None, None)).1 = Some(item);
+    }
+    items
+}
+
+#[derive(Default)]
+struct Changes(Vec<Change>);
+
+impl Changes {
+    fn breaking(&mut self, item: String, change: String) {
+        self.0.push(Change {
+            severity: Severity::Breaking,
+            item,
+            change,
+        });
+    }
+
+    fn additive(&mut self, item: String, change: String) {
+        self.0.push(Change {
+            severity: Severity::Additive,
+            item,
+            change,
+        });
+    }
+
+    fn discriminator(&mut self, item: &str, old: &[u8], new: &[u8]) {
+        if old != new {
+            self.breaking(
+                item.to_string(),
+                format!(
+                    "discriminator changed from {} to {}",
+                    hex::encode(old),
+                    hex::encode(new)
+                ),
+            );
+        }
+    }
+
+    /// Compares Borsh-encoded fields, where only renames keep the layout.
+    /// With `appendable`, as for enum variants, new trailing entries keep
+    /// existing values decodable.
+    fn fields(
+        &mut self,
+        item: &str,
+        label: &str,
+        old: &[FieldReport],
+        new: &[FieldReport],
+        appendable: bool,
+    ) {
+        if old == new {
+            return;
+        }
+        let position =
+            |fields: &[FieldReport], name: &str| fields.iter().position(|field| field.name == name);
+
+        // Same type at the same position under a name the other version lacks.
+        let renamed = |index: usize| {
+            let (Some(old_field), Some(new_field)) = (old.get(index), new.get(index)) else {
+                return false;
+            };
+            old_field.name != new_field.name
+                && old_field.rust_type == new_field.rust_type
+                && position(new, &old_field.name).is_none()
+                && position(old, &new_field.name).is_none()
+        };
+        for (index, old_field) in old.iter().enumerate() {
+            if renamed(index) {
+                self.additive(
+                    item.to_string(),
+                    format!(
+                        "{label} `{}` renamed to `{}`",
+                        old_field.name, new[index].name
+                    ),
+                );
+            }
+        }
+
+        let appended = appendable && new.starts_with(old);
+        for (index, old_field) in old.iter().enumerate() {
+            if renamed(index) {
+                continue;
+            }
+            match position(new, &old_field.name) {
+                None => self.breaking(
+                    item.to_string(),
+                    format!("{label} `{}` removed", old_field.name),
+                ),
+                Some(new_index) if new[new_index].rust_type != old_field.rust_type => self
+                    .breaking(
+                        item.to_string(),
+                        format!(
+                            "type of {label} `{}` changed from `{}` to `{}`",
+                            old_field.name, old_field.rust_type, new[new_index].rust_type
+                        ),
+                    ),
+                Some(_) => {}
+            }
+        }
+        for (index, new_field) in new.iter().enumerate() {
+            if renamed(index) || position(old, &new_field.name).is_some() {
+                continue;
+            }
+            let change = format!("{label} `{}` added", new_field.name);
+            if appended {
+                self.additive(item.to_string(), change);
+            } else {
+                self.breaking(item.to_string(), change);
+            }
+        }
+
+        let order = |fields: &[FieldReport], other: &[FieldReport]| {
+            fields
+                .iter()
+                .filter(|field| position(other, &field.name).is_some())
+                .map(|field| field.name.clone())
+                .collect::<Vec<_>>()
+        };
+        if order(old, new) != order(new, old) {
+            self.breaking(item.to_string(), format!("{label}s reordered"));
+        }
+    }
+
+    /// Accounts are passed by position and fixed addresses are baked into the
+    /// encoder, so any change is breaking.
+    fn instruction_accounts(
+        &mut self,
+        item: &str,
+        old: &[InstructionAccountReport],
+        new: &[InstructionAccountReport],
+    ) {
+        let find = |accounts: &'_ [InstructionAccountReport], name: &str| {
+            accounts.iter().position(|account| account.name == name)
+        };
+
+        for old_account in old {
+            let Some(new_index) = find(new, &old_account.name) else {
+                self.breaking(
+                    item.to_string(),
+                    format!("account `{}` removed", old_account.name),
+                );
+                continue;
+            };
+            let new_account = &new[new_index];
+            for (flag, was, is) in [
+                ("writable", old_account.writable, new_account.writable),
+                ("signer", old_account.signer, new_account.signer),
+                ("optional", old_account.optional, new_account.optional),
+            ] {
+                if was != is {
+                    let now = if is { "now" } else { "no longer" };
+                    self.breaking(
+                        item.to_string(),
+                        format!("account `{}` is {now} {flag}", old_account.name),
+                    );
+                }
+            }
+            if old_account.address != new_account.address {
+                self.breaking(
+                    item.to_string(),
+                    format!("address of account `{}` changed", old_account.name),
+                );
+            }
+        }
+        for new_account in new {
+            if find(old, &new_account.name).is_none() {
+                self.breaking(
+                    item.to_string(),
+                    format!("account `{}` added", new_account.name),
+                );
+            }
+        }
+
+        let order = |accounts: &[InstructionAccountReport], other: &[InstructionAccountReport]| {
+            accounts
+                .iter()
+                .filter(|account| find(other, &account.name).is_some())
+                .map(|account| account.name.clone())
+                .collect::<Vec<_>>()
+        };
+        if order(old, new) != order(new, old) {
+            self.breaking(item.to_string(), "accounts reordered".to_string());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::handlers::ErrorReport};
+
+    const TOKEN_MESSENGER: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../idls/token_messenger_minter_v2.json"
+    );
+
+    #[test]
+    fn test_diff_reports() {
+        let old = inspect_idl(TOKEN_MESSENGER, None).unwrap();
+        assert!(diff_reports(&old, &old).is_empty());
+
+        let mut new = inspect_idl(TOKEN_MESSENGER, None).unwrap();
+        new.instructions[0].discriminator = vec![0; 8];
+        new.instructions[1].accounts.swap(0, 1);
+        new.errors.push(ErrorReport {
+            code: 7000,
+            name: "Added".to_string(),
+            message: String::new(),
+        });
+        let token_pair = new
+            .accounts
+            .iter_mut()
+            .find(|account| account.name == "TokenPair")
+            .unwrap();
+        token_pair.fields[0].name = "renamed".to_string();
+        token_pair.fields.swap(1, 2);
+
+        let changes = diff_reports(&old, &new);
+        let summary: Vec<_> = changes
+            .iter()
+            .map(|change| (change.severity, change.change.as_str()))
+            .collect();
+        assert_eq!(summary, vec![
+            (
+                Severity::Breaking,
+                "discriminator changed from ac172b0deed55596 to 0000000000000000"
+            ),
+            (Severity::Breaking, "accounts reordered"),
+            (
+                Severity::Additive,
+                "field `remote_domain` renamed to `renamed`"
+            ),
+            (Severity::Breaking, "fields reordered"),
+            (Severity::Additive, "`Added` added"),
+        ]);
+    }
+
+    #[test]
+    fn test_diff_instruction_accounts() {
+        let account = |name: &str| InstructionAccountReport {
+            name: name.to_string(),
+            writable: false,
+            signer: false,
+            optional: false,
+            address: None,
+        };
+        let old = vec![
+            account("payer"),
+            account("counter"),
+            account("system_program"),
+        ];
+        let mut new = vec![
+            account("counter"),
+            account("payer"),
+            account("system_program"),
+            account("event_authority"),
+        ];
+        new[2].address = Some("11111111111111111111111111111111".to_string());
+
+        let mut changes = Changes::default();
+        changes.instruction_accounts("initialize", &old, &new);
+        let summary: Vec<_> = changes
+            .0
+            .iter()
+            .map(|change| (change.severity, change.change.as_str()))
+            .collect();
+        assert_eq!(summary, vec![
+            (
+                Severity::Breaking,
+                "address of account `system_program` changed"
+            ),
+            (Severity::Breaking, "account `event_authority` added"),
+            (Severity::Breaking, "accounts reordered"),
+        ]);
+    }
+}
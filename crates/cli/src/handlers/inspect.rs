@@ -0,0 +1,787 @@
+use {
+    crate::{
+        accounts::{legacy_process_accounts, process_accounts, shank_process_accounts},
+        commands::IdlStandard,
+        error::NitrogenError,
+        events::EventData,
+        handlers::{ProgramData, inspect_codama},
+        idl::IdlEnumField,
+        instructions::{
+            Discriminator,
+            InstructionData,
+            PdaSeed,
+            legacy_compute_instruction_discriminator,
+            legacy_process_instructions,
+            process_instructions,
+        },
+        program_errors::ErrorData,
+        types::{
+            EnumVariantFields,
+            FieldData,
+            TypeData,
+            TypeKind,
+            legacy_process_types,
+            process_types,
+        },
+        util::{
+            anchor_fixed_size,
+            detect_idl_standard,
+            idl_type_to_rust_type,
+            legacy_fixed_size,
+            legacy_read_idl,
+            read_idl,
+        },
+    },
+    anyhow::Result,
+    heck::{ToSnakeCase, ToUpperCamelCase},
+    serde::Serialize,
+    sha2::{Digest, Sha256},
+    std::{collections::HashSet, fmt},
+};
+
+/// What an encoder would be generated from, for any IDL standard.
+#[derive(Debug, Serialize)]
+pub struct ProgramReport {
+    pub name: String,
+    pub address: String,
+    pub standard: String,
+    pub instructions: Vec<InstructionReport>,
+    pub pdas: Vec<PdaReport>,
+    pub accounts: Vec<AccountReport>,
+    pub types: Vec<TypeReport>,
+    pub events: Vec<EventReport>,
+    pub errors: Vec<ErrorReport>,
+    /// Constructs the generator doesn't support yet.
+    pub issues: Vec<Issue>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct InstructionReport {
+    pub name: String,
+    pub discriminator: Vec<u8>,
+    pub args: Vec<FieldReport>,
+    pub accounts: Vec<InstructionAccountReport>,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct FieldReport {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub rust_type: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct InstructionAccountReport {
+    pub name: String,
+    pub writable: bool,
+    pub signer: bool,
+    pub optional: bool,
+    pub address: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PdaReport {
+    pub instruction: String,
+    pub account: String,
+    /// Expressions of the seed bytes in the generated encoder.
+    pub seeds: Vec<String>,
+    pub program: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AccountReport {
+    pub name: String,
+    pub discriminator: Vec<u8>,
+    /// Size of the account data, discriminator included, when fixed.
+    pub size: Option<usize>,
+    pub fields: Vec<FieldReport>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TypeReport {
+    pub name: String,
+    pub kind: String,
+    /// Fields of structs, or the variants of enums typed by their fields.
+    pub fields: Vec<FieldReport>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct EventReport {
+    pub name: String,
+    pub discriminator: Vec<u8>,
+    pub fields: Vec<FieldReport>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ErrorReport {
+    pub code: u32,
+    pub name: String,
+    pub message: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Issue {
+    pub item: String,
+    pub construct: String,
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Unsupported {} in `{}`", self.construct, self.item)
+    }
+}
+
+/// Prints what the encoder of the IDL at `path` would contain, flagging the
+/// constructs it can't be generated from.
+pub fn inspect(path: String, standard: Option<IdlStandard>, json: bool) -> Result<()> {
+    let report = inspect_idl(&path, standard)?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        print_report(&report);
+    }
+
+    Ok(())
+}
+
+/// Reads the IDL at `path`, detecting its standard when `standard` is
+/// `None`. Codama IDLs are reported for their main program.
+pub fn inspect_idl(path: &str, standard: Option<IdlStandard>) -> Result<ProgramReport> {
+    let standard = match standard {
+        Some(standard) => standard,
+        None => detect_idl_standard(path)?,
+    };
+    let (program, issues) = match standard {
+        IdlStandard::Anchor => inspect_anchor(path)?,
+        IdlStandard::AnchorLegacy | IdlStandard::Shank => inspect_legacy(path, standard)?,
+        IdlStandard::Codama => inspect_codama(path)?,
+    };
+    Ok(ProgramReport::new(&program, standard, issues))
+}
+
+fn inspect_anchor(path: &str) -> Result<(ProgramData, Vec<Issue>)> {
+    let mut idl = read_idl(path)?;
+    let mut issues = Vec::new();
+
+    idl.types.retain(|type_def| {
+        let kind = type_def.type_.kind.as_str();
+        let supported = matches!(kind, "struct" | "tuple_struct" | "enum");
+        if !supported {
+            issues.push(Issue {
+                item: type_def.name.clone(),
+                construct: format!("type kind `{kind}`"),
+            });
+        }
+        supported
+    });
+    let types = process_types(&idl, None)?;
+
+    let instructions = process_instructions(&idl, &[]);
+    for instruction in &instructions {
+        issues.extend(unresolved_seeds(instruction, &types));
+    }
+
+    let mut accounts = process_accounts(&idl, &[]);
+    for account in &mut accounts {
+        account.size = idl
+            .types
+            .iter()
+            .find(|type_def| type_def.name == account.struct_name)
+            .and_then(|type_def| {
+                type_def.type_.fields.iter().flatten().try_fold(
+                    account.discriminator.0.len(),
+                    |size, field| {
+                        let field_type = match field {
+                            IdlEnumField::Named(field) => &field.type_,
+                            IdlEnumField::Tuple(field) => field,
+                        };
+                        Some(size + anchor_fixed_size(&idl, field_type)?)
+                    },
+                )
+            });
+    }
+
+    let events = idl
+        .events
+        .iter()
+        .map(|event| EventData {
+            type_data: types
+                .iter()
+                .find(|type_data| type_data.name == event.name)
+                .cloned()
+                .unwrap_or_else(|| TypeData {
+                    name: event.name.clone(),
+                    fields: Vec::new(),
+                    kind: TypeKind::Struct,
+                    requires_imports: false,
+                }),
+            discriminator: Discriminator(event.discriminator.clone()),
+        })
+        .collect();
+    let errors = idl
+        .errors
+        .iter()
+        .map(|error| ErrorData {
+            name: error.name.to_upper_camel_case(),
+            code: error.code,
+            message: error.msg.clone().unwrap_or_default(),
+        })
+        .collect();
+
+    let program = ProgramData {
+        accounts,
+        instructions,
+        types,
+        errors,
+        events,
+        imported_types: Vec::new(),
+        program_dependencies: Vec::new(),
+        name: idl.metadata.name,
+        address: idl.address,
+    };
+    Ok((program, issues))
+}
+
+fn inspect_legacy(path: &str, standard: IdlStandard) -> Result<(ProgramData, Vec<Issue>)> {
+    let mut idl = legacy_read_idl(path)?;
+    let mut issues = Vec::new();
+
+    for type_def in &idl.types {
+        let kind = type_def.type_.kind.as_str();
+        if !matches!(kind, "struct" | "enum") {
+            issues.push(Issue {
+                item: type_def.name.clone(),
+                construct: format!("type kind `{kind}`"),
+            });
+        }
+    }
+
+    let mut unsupported = HashSet::new();
+    for instruction in &idl.instructions {
+        match legacy_compute_instruction_discriminator(
+            &instruction.name.to_snake_case(),
+            instruction.discriminant.as_ref(),
+        ) {
+            Ok(_) => {}
+            Err(NitrogenError::Unsupported { item, construct }) => {
+                issues.push(Issue { item, construct });
+                unsupported.insert(instruction.name.clone());
+            }
+            Err(e) => return Err(e.into()),
+        }
+    }
+    idl.instructions
+        .retain(|instruction| !unsupported.contains(&instruction.name));
+
+    let mut accounts = if standard == IdlStandard::Shank {
+        shank_process_accounts(&idl, &[])
+    } else {
+        legacy_process_accounts(&idl, &[])
+    };
+    for account in &mut accounts {
+        if account.size.is_some() {
+            continue;
+        }
+        account.size = idl
+            .accounts
+            .iter()
+            .find(|idl_account| idl_account.name.to_upper_camel_case() == account.struct_name)
+            .and_then(|idl_account| {
+                idl_account
+                    .type_
+                    .fields
+                    .iter()
+                    .flatten()
+                    .try_fold(account.discriminator.0.len(), |size, field| {
+                        Some(size + legacy_fixed_size(&idl, &field.type_)?)
+                    })
+            });
+    }
+
+    let events = idl
+        .events
+        .iter()
+        .map(|event| EventData {
+            type_data: TypeData {
+                name: event.name.to_upper_camel_case(),
+                fields: event
+                    .fields
+                    .iter()
+                    .map(|field| {
+                        let (rust_type, _) = idl_type_to_rust_type(&field.type_);
+                        FieldData {
+                            name: field.name.to_snake_case(),
+                            is_pubkey: rust_type.contains("Pubkey"),
+                            rust_type,
+                            attributes: None,
+                        }
+                    })
+                    .collect(),
+                kind: TypeKind::Struct,
+                requires_imports: false,
+            },
+            discriminator: Discriminator(
+                Sha256::digest(format!("event:{}", event.name))[..8].to_vec(),
+            ),
+        })
+        .collect();
+    let errors = idl
+        .errors
+        .iter()
+        .map(|error| ErrorData {
+            name: error.name.to_upper_camel_case(),
+            code: error.code as u32,
+            message: error.msg.clone().unwrap_or_default(),
+        })
+        .collect();
+
+    let program = ProgramData {
+        accounts,
+        instructions: legacy_process_instructions(&idl, &[])?,
+        types: legacy_process_types(&idl, None),
+        errors,
+        events,
+        imported_types: Vec::new(),
+        program_dependencies: Vec::new(),
+        address: idl
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.address.clone())
+            .unwrap_or_default(),
+        name: idl.name,
+    };
+    Ok((program, issues))
+}
+
+/// PDA seeds of `instruction` that the generated encoder can't derive,
+/// e.g. paths to accounts that aren't parameters or to integer fields.
+fn unresolved_seeds(instruction: &InstructionData, types: &[TypeData]) -> Vec<Issue> {
+    let field_type = |type_name: &str, field: &str| {
+        types
+            .iter()
+            .find(|type_data| type_data.name == type_name)?
+            .fields
+            .iter()
+            .find(|type_field| type_field.name == field)
+            .map(|type_field| type_field.rust_type.clone())
+    };
+
+    let mut issues = Vec::new();
+    for account in &instruction.accounts {
+        let Some(pda) = &account.pda else {
+            continue;
+        };
+        for seed in &pda.seeds {
+            let resolved = if seed.value.is_some() || seed.expr.is_some() {
+                true
+            } else if let Some(param_type) = &seed.param_type {
+                // `self.params.<field>` for argument paths.
+                let rust_type = match &param_type.rust_type {
+                    Some(rust_type) => Some(rust_type.clone()),
+                    None => instruction
+                        .args
+                        .iter()
+                        .find(|arg| arg.name == "params")
+                        .map(|arg| arg.rust_type.clone()),
+                };
+                rust_type
+                    .and_then(|rust_type| field_type(&rust_type, &param_type.field))
+                    .is_some_and(|rust_type| is_seed_ref(&rust_type))
+            } else if let Some(path) = &seed.path {
+                instruction
+                    .accounts
+                    .iter()
+                    .any(|other| other.name == *path && other.is_param())
+            } else {
+                false
+            };
+            if !resolved {
+                issues.push(Issue {
+                    item: format!("{}.{}", instruction.module_name, account.name),
+                    construct: format!(
+                        "{} seed `{}`",
+                        seed.kind,
+                        seed.path.as_deref().unwrap_or_default()
+                    ),
+                });
+            }
+        }
+    }
+    issues
+}
+
+/// Values of `rust_type` borrow as seed bytes with `.as_ref()`.
+fn is_seed_ref(rust_type: &str) -> bool {
+    !matches!(
+        rust_type,
+        "bool"
+            | "u8"
+            | "i8"
+            | "u16"
+            | "i16"
+            | "u32"
+            | "i32"
+            | "u64"
+            | "i64"
+            | "u128"
+            | "i128"
+            | "f32"
+            | "f64"
+    )
+}
+
+impl ProgramReport {
+    fn new(program: &ProgramData, standard: IdlStandard, issues: Vec<Issue>) -> Self {
+        let field_reports = |fields: &[FieldData]| {
+            fields
+                .iter()
+                .map(|field| FieldReport {
+                    name: field.name.clone(),
+                    rust_type: field.rust_type.clone(),
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let mut pdas = Vec::new();
+        for instruction in &program.instructions {
+            for account in &instruction.accounts {
+                if let Some(pda) = &account.pda {
+                    pdas.push(PdaReport {
+                        instruction: instruction.module_name.clone(),
+                        account: account.name.clone(),
+                        seeds: pda.seeds.iter().map(seed_expr).collect(),
+                        program: pda.program.clone(),
+                    });
+                }
+            }
+        }
+
+        ProgramReport {
+            name: program.name.clone(),
+            address: program.address.clone(),
+            standard: standard.to_string(),
+            instructions: program
+                .instructions
+                .iter()
+                .map(|instruction| InstructionReport {
+                    name: instruction.module_name.clone(),
+                    discriminator: instruction.discriminator.0.clone(),
+                    args: instruction
+                        .args
+                        .iter()
+                        .map(|arg| FieldReport {
+                            name: arg.name.clone(),
+                            rust_type: arg.rust_type.clone(),
+                        })
+                        .collect(),
+                    accounts: instruction
+                        .accounts
+                        .iter()
+                        .map(|account| InstructionAccountReport {
+                            name: account.name.clone(),
+                            writable: account.is_mut,
+                            signer: account.is_signer,
+                            optional: account.is_optional,
+                            address: account.address.clone(),
+                        })
+                        .collect(),
+                })
+                .collect(),
+            pdas,
+            accounts: program
+                .accounts
+                .iter()
+                .map(|account| AccountReport {
+                    name: account.struct_name.clone(),
+                    discriminator: account.discriminator.0.clone(),
+                    size: account.size,
+                    fields: account
+                        .fields
+                        .iter()
+                        .map(|field| FieldReport {
+                            name: field.name.clone(),
+                            rust_type: field.rust_type.clone(),
+                        })
+                        .collect(),
+                })
+                .collect(),
+            types: program
+                .types
+                .iter()
+                .map(|type_data| {
+                    let (kind, fields) = match &type_data.kind {
+                        TypeKind::Struct => ("struct", field_reports(&type_data.fields)),
+                        TypeKind::TupleStruct => ("tuple_struct", field_reports(&type_data.fields)),
+                        TypeKind::Enum(variants) => (
+                            "enum",
+                            variants
+                                .iter()
+                                .map(|variant| FieldReport {
+                                    name: variant.name.clone(),
+                                    rust_type: match &variant.fields {
+                                        Some(EnumVariantFields::Named(fields)) => format!(
+                                            "{{ {} }}",
+                                            fields
+                                                .iter()
+                                                .map(|field| format!(
+                                                    "{}: {}",
+                                                    field.name, field.rust_type
+                                                ))
+                                                .collect::<Vec<_>>()
+                                                .join(", ")
+                                        ),
+                                        Some(EnumVariantFields::Unnamed(fields)) => format!(
+                                            "({})",
+                                            fields
+                                                .iter()
+                                                .map(|field| field.rust_type.as_str())
+                                                .collect::<Vec<_>>()
+                                                .join(", ")
+                                        ),
+                                        None => String::new(),
+                                    },
+                                })
+                                .collect(),
+                        ),
+                    };
+                    TypeReport {
+                        name: type_data.name.clone(),
+                        kind: kind.to_string(),
+                        fields,
+                    }
+                })
+                .collect(),
+            events: program
+                .events
+                .iter()
+                .map(|event| EventReport {
+                    name: event.type_data.name.clone(),
+                    discriminator: event.discriminator.0.clone(),
+                    fields: field_reports(&event.type_data.fields),
+                })
+                .collect(),
+            errors: program
+                .errors
+                .iter()
+                .map(|error| ErrorReport {
+                    code: error.code,
+                    name: error.name.clone(),
+                    message: error.message.clone(),
+                })
+                .collect(),
+            issues,
+        }
+    }
+}
+
+/// The seed as the generated encoder derives it.
+fn seed_expr(seed: &PdaSeed) -> String {
+    if let Some(param_type) = &seed.param_type {
+        param_type.to_string()
+    } else if let Some(value) = &seed.value {
+        value.to_string()
+    } else if let Some(path) = &seed.path {
+        format!("{path}.as_ref()")
+    } else {
+        seed.expr.clone().unwrap_or_default()
+    }
+}
+
+fn print_report(report: &ProgramReport) {
+    println!("{} ({}, {})", report.name, report.address, report.standard);
+
+    print_table(
+        "Instructions",
+        &["NAME", "DISCRIMINATOR", "ARGS"],
+        report
+            .instructions
+            .iter()
+            .map(|instruction| {
+                vec![
+                    instruction.name.clone(),
+                    hex::encode(&instruction.discriminator),
+                    fields_summary(&instruction.args),
+                ]
+            })
+            .collect(),
+    );
+    print_table(
+        "Instruction accounts",
+        &["INSTRUCTION", "ACCOUNT", "FLAGS", "ADDRESS"],
+        report
+            .instructions
+            .iter()
+            .flat_map(|instruction| {
+                instruction.accounts.iter().map(|account| {
+                    let flags = [
+                        (account.writable, "writable"),
+                        (account.signer, "signer"),
+                        (account.optional, "optional"),
+                    ]
+                    .into_iter()
+                    .filter_map(|(set, flag)| set.then_some(flag))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                    vec![
+                        instruction.name.clone(),
+                        account.name.clone(),
+                        flags,
+                        account.address.clone().unwrap_or_default(),
+                    ]
+                })
+            })
+            .collect(),
+    );
+    print_table(
+        "PDAs",
+        &["INSTRUCTION", "ACCOUNT", "SEEDS", "PROGRAM"],
+        report
+            .pdas
+            .iter()
+            .map(|pda| {
+                vec![
+                    pda.instruction.clone(),
+                    pda.account.clone(),
+                    pda.seeds.join(", "),
+                    pda.program.clone().unwrap_or_default(),
+                ]
+            })
+            .collect(),
+    );
+    print_table(
+        "Accounts",
+        &["NAME", "DISCRIMINATOR", "SIZE"],
+        report
+            .accounts
+            .iter()
+            .map(|account| {
+                vec![
+                    account.name.clone(),
+                    hex::encode(&account.discriminator),
+                    account
+                        .size
+                        .map_or_else(|| "variable".to_string(), |size| size.to_string()),
+                ]
+            })
+            .collect(),
+    );
+    print_table(
+        "Events",
+        &["NAME", "DISCRIMINATOR", "FIELDS"],
+        report
+            .events
+            .iter()
+            .map(|event| {
+                vec![
+                    event.name.clone(),
+                    hex::encode(&event.discriminator),
+                    fields_summary(&event.fields),
+                ]
+            })
+            .collect(),
+    );
+    print_table(
+        "Errors",
+        &["CODE", "NAME", "MESSAGE"],
+        report
+            .errors
+            .iter()
+            .map(|error| {
+                vec![
+                    error.code.to_string(),
+                    error.name.clone(),
+                    error.message.clone(),
+                ]
+            })
+            .collect(),
+    );
+    print_table(
+        "Issues",
+        &["ITEM", "UNSUPPORTED"],
+        report
+            .issues
+            .iter()
+            .map(|issue| vec![issue.item.clone(), issue.construct.clone()])
+            .collect(),
+    );
+}
+
+fn fields_summary(fields: &[FieldReport]) -> String {
+    fields
+        .iter()
+        .map(|field| format!("{}: {}", field.name, field.rust_type))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Prints `rows` under `headers` with aligned columns, the last one unpadded.
+pub fn print_table(title: &str, headers: &[&str], rows: Vec<Vec<String>>) {
+    println!("\n{title} ({})", rows.len());
+    if rows.is_empty() {
+        return;
+    }
+
+    let mut widths: Vec<usize> = headers.iter().map(|header| header.len()).collect();
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+    let print_row = |cells: Vec<&str>| {
+        let last = cells.len() - 1;
+        let line = cells
+            .iter()
+            .enumerate()
+            .map(|(index, cell)| {
+                if index == last {
+                    cell.to_string()
+                } else {
+                    format!("{cell:<width$}", width = widths[index])
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("  ");
+        println!("  {}", line.trim_end());
+    };
+
+    print_row(headers.to_vec());
+    for row in &rows {
+        print_row(row.iter().map(String::as_str).collect());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const IDLS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../../idls");
+
+    #[test]
+    fn test_inspect_idl() {
+        let report = inspect_idl(&format!("{IDLS}/shank_vault.json"), None).unwrap();
+        assert_eq!(report.standard, "shank");
+        let sizes: Vec<_> = report.accounts.iter().map(|account| account.size).collect();
+        assert_eq!(sizes, vec![Some(48), None]);
+        assert!(report.issues.is_empty());
+
+        let report = inspect_idl(&format!("{IDLS}/token_messenger_minter_v2.json"), None).unwrap();
+        let deposit = report
+            .instructions
+            .iter()
+            .find(|instruction| instruction.name == "deposit_for_burn")
+            .unwrap();
+        assert_eq!(deposit.discriminator.len(), 8);
+        assert!(
+            report
+                .pdas
+                .iter()
+                .any(|pda| pda.instruction == "deposit_for_burn")
+        );
+        // Integer fields of the params don't borrow as seed bytes.
+        assert!(report.issues.iter().any(|issue| {
+            issue.item == "link_token_pair.token_pair"
+                && issue.construct == "arg seed `params.remote_domain`"
+        }));
+    }
+}
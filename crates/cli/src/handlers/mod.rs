@@ -4,5 +4,17 @@ pub use parse::*;
 mod codama;
 pub use codama::*;
 
+mod convert_idl;
+pub use convert_idl::*;
+
+mod idl_diff;
+pub use idl_diff::*;
+
+mod generate;
+pub use generate::*;
+
+mod inspect;
+pub use inspect::*;
+
 mod process_pda_idl;
 pub use process_pda_idl::*;
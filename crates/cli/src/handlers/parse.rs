@@ -1,54 +1,296 @@
 use {
     crate::{
-        accounts::{AccountsModTemplate, AccountsStructTemplate, process_accounts},
-        instructions::{InstructionsModTemplate, InstructionsStructTemplate, process_instructions},
-        types::{TypeStructTemplate, process_types},
-        util::{is_big_array, read_idl},
+        accounts::{
+            AccountData,
+            AccountsModTemplate,
+            AccountsStructTemplate,
+            ClientTemplate,
+            legacy_process_accounts,
+            process_accounts,
+            shank_process_accounts,
+        },
+        config::Overrides,
+        error::NitrogenError,
+        events::{EventData, EventsModTemplate, EventsStructTemplate},
+        instructions::{
+            InstructionData,
+            InstructionsModTemplate,
+            InstructionsStructTemplate,
+            legacy_process_instructions,
+            process_instructions,
+        },
+        legacy_idl::LegacyIdl,
+        program_errors::{ErrorData, ErrorsTemplate},
+        types::{
+            TypeData,
+            TypeStructTemplate,
+            legacy_process_types,
+            legacy_reachable_types,
+            process_types,
+            reachable_types,
+        },
+        util::{
+            apply_human_readable_serde,
+            format_generated_code,
+            legacy_read_idl,
+            needs_big_array,
+            parse_filter,
+            read_idl,
+            serde_features,
+            uses_codec,
+        },
     },
     anyhow::{Result, bail},
     askama::Template,
     heck::{ToKebabCase, ToSnakeCase, ToUpperCamelCase},
-    std::fs::{self},
+    serde::{Deserialize, Serialize},
+    similar::TextDiff,
+    std::{
+        collections::BTreeMap,
+        fs::{self},
+        path::Path,
+        slice,
+    },
 };
 
+/// Program data extracted from an IDL, ready to be rendered into an encoder.
+pub struct ProgramData {
+    pub name: String,
+    pub address: String,
+    pub accounts: Vec<AccountData>,
+    pub instructions: Vec<InstructionData>,
+    pub types: Vec<TypeData>,
+    pub errors: Vec<ErrorData>,
+    pub events: Vec<EventData>,
+    /// Paths of types defined by other generated programs, re-exported from
+    /// the `types` module.
+    pub imported_types: Vec<String>,
+    /// Package names of the generated crates defining `imported_types`.
+    pub program_dependencies: Vec<String>,
+}
+
+/// First line of every generated Rust file, which are the only ones ever
+/// deleted.
+const GENERATED_MARKER: &str = "@generated by nitrogen";
+
+/// Lists the files generated into a crate or module directory, to delete
+/// those no longer generated on the next run.
+const MANIFEST_FILENAME: &str = ".nitrogen-manifest.json";
+
+#[derive(Serialize, Deserialize)]
+struct Manifest {
+    /// Paths relative to the crate or module directory.
+    files: Vec<String>,
+}
+
+/// Files touched when generating encoders.
+#[derive(Debug, Default)]
+pub struct FileChanges {
+    pub created: Vec<String>,
+    pub changed: Vec<String>,
+    pub deleted: Vec<String>,
+    pub unchanged: usize,
+}
+
+impl FileChanges {
+    pub fn extend(&mut self, other: FileChanges) {
+        self.created.extend(other.created);
+        self.changed.extend(other.changed);
+        self.deleted.extend(other.deleted);
+        self.unchanged += other.unchanged;
+    }
+}
+
+/// Builds the accounts of a legacy-layout IDL.
+type ProcessLegacyAccounts = fn(&LegacyIdl, &[String]) -> Vec<AccountData>;
+
 pub fn parse(
     path: String,
     output: String,
     crate_name: Option<String>,
     filter: Option<String>,
-) -> Result<()> {
-    let filter_vec: Vec<String> = filter
-        .map(|f| f.split(',').map(|s| s.trim().to_string()).collect())
-        .unwrap_or_default();
-
-    let (accounts_data, instructions_data, types_data, program_name, program_id) =
-        match read_idl(&path) {
-            Ok(idl) => {
-                let accounts_data = process_accounts(&idl);
-                let instructions_data = process_instructions(&idl, &filter_vec);
-                let types_data = process_types(&idl);
-                let program_name = idl.metadata.name;
-                let program_id = idl.address;
-
-                (
-                    accounts_data,
-                    instructions_data,
-                    types_data,
-                    program_name,
-                    program_id,
-                )
-            }
-            Err(idl_err) => {
-                bail!("{idl_err}");
-            }
-        };
+    account_filter: Option<String>,
+    event_filter: Option<String>,
+    human_readable: bool,
+    check: bool,
+    overrides: &Overrides,
+) -> Result<FileChanges> {
+    let filter_vec = parse_filter(filter);
+    let account_filter = parse_filter(account_filter);
+    let event_filter = parse_filter(event_filter);
+
+    let idl = read_idl(&path)?;
+    let program = ProgramData {
+        accounts: process_accounts(&idl, &account_filter),
+        instructions: process_instructions(&idl, &filter_vec),
+        types: process_types(
+            &idl,
+            reachable_types(&idl, &filter_vec, &account_filter, &event_filter).as_ref(),
+        )?,
+        errors: Vec::new(),
+        events: Vec::new(),
+        imported_types: Vec::new(),
+        program_dependencies: Vec::new(),
+        name: idl.metadata.name,
+        address: idl.address,
+    };
+
+    generate_encoder(
+        program,
+        output,
+        crate_name,
+        human_readable,
+        check,
+        overrides,
+    )
+}
+
+/// Generates the encoder of a legacy (pre-0.30) Anchor IDL.
+pub fn parse_legacy(
+    path: String,
+    output: String,
+    crate_name: Option<String>,
+    filter: Option<String>,
+    account_filter: Option<String>,
+    event_filter: Option<String>,
+    human_readable: bool,
+    check: bool,
+    overrides: &Overrides,
+) -> Result<FileChanges> {
+    parse_legacy_layout(
+        path,
+        output,
+        crate_name,
+        filter,
+        account_filter,
+        event_filter,
+        human_readable,
+        check,
+        overrides,
+        legacy_process_accounts,
+    )
+}
+
+/// Generates the encoder of a Shank IDL, whose native accounts have no
+/// discriminator.
+pub fn parse_shank(
+    path: String,
+    output: String,
+    crate_name: Option<String>,
+    filter: Option<String>,
+    account_filter: Option<String>,
+    event_filter: Option<String>,
+    human_readable: bool,
+    check: bool,
+    overrides: &Overrides,
+) -> Result<FileChanges> {
+    parse_legacy_layout(
+        path,
+        output,
+        crate_name,
+        filter,
+        account_filter,
+        event_filter,
+        human_readable,
+        check,
+        overrides,
+        shank_process_accounts,
+    )
+}
+
+/// Shared by the IDL standards using the legacy Anchor layout, which differ
+/// in how accounts are identified.
+fn parse_legacy_layout(
+    path: String,
+    output: String,
+    crate_name: Option<String>,
+    filter: Option<String>,
+    account_filter: Option<String>,
+    event_filter: Option<String>,
+    human_readable: bool,
+    check: bool,
+    overrides: &Overrides,
+    process_accounts: ProcessLegacyAccounts,
+) -> Result<FileChanges> {
+    let filter_vec = parse_filter(filter);
+    let account_filter = parse_filter(account_filter);
+    let event_filter = parse_filter(event_filter);
+
+    let idl = legacy_read_idl(&path)?;
+    let Some(address) = idl
+        .metadata
+        .as_ref()
+        .and_then(|metadata| metadata.address.clone())
+    else {
+        bail!("IDL has no `metadata.address` to declare the program id with");
+    };
+    let program = ProgramData {
+        accounts: process_accounts(&idl, &account_filter),
+        instructions: legacy_process_instructions(&idl, &filter_vec)?,
+        types: legacy_process_types(
+            &idl,
+            legacy_reachable_types(&idl, &filter_vec, &account_filter, &event_filter).as_ref(),
+        ),
+        errors: Vec::new(),
+        events: Vec::new(),
+        imported_types: Vec::new(),
+        program_dependencies: Vec::new(),
+        name: idl.name,
+        address,
+    };
+
+    generate_encoder(
+        program,
+        output,
+        crate_name,
+        human_readable,
+        check,
+        overrides,
+    )
+}
+
+/// Writes the encoder for `program` into `output`, as a crate when
+/// `crate_name` is set and as a module otherwise.
+pub fn generate_encoder(
+    mut program: ProgramData,
+    output: String,
+    crate_name: Option<String>,
+    human_readable: bool,
+    check: bool,
+    overrides: &Overrides,
+) -> Result<FileChanges> {
+    overrides.apply(&mut program)?;
+    let ProgramData {
+        name: program_name,
+        address: program_id,
+        accounts: mut accounts_data,
+        instructions: mut instructions_data,
+        types: mut types_data,
+        errors: errors_data,
+        events: mut events_data,
+        imported_types,
+        program_dependencies,
+    } = program;
+
+    if human_readable {
+        apply_human_readable_serde(&mut types_data, &mut accounts_data, &mut instructions_data);
+        for event in &mut events_data {
+            apply_human_readable_serde(slice::from_mut(&mut event.type_data), &mut [], &mut []);
+        }
+    }
 
     let encoder_name = format!("{}Encoder", program_name.to_upper_camel_case());
     let program_struct_name = format!("{}Account", program_name.to_upper_camel_case());
     let program_instruction_enum = format!("{}Instruction", program_name.to_upper_camel_case());
+    let program_event_enum = format!("{}Event", program_name.to_upper_camel_case());
+    let error_enum = format!("{}Error", program_name.to_upper_camel_case());
 
     let crate_dir = match &crate_name {
-        Some(name) => format!("{}/{}", output.trim_end_matches('/'), name),
+        Some(name) => format!(
+            "{}/{}",
+            output.trim_end_matches('/'),
+            overrides.directory.as_deref().unwrap_or(name)
+        ),
         None => format!(
             "{}/{}_encoder",
             output.trim_end_matches('/'),
@@ -56,36 +298,34 @@ pub fn parse(
         ),
     };
 
-    fs::create_dir_all(&crate_dir).expect("Failed to create encoder directory");
-
     let src_dir = if crate_name.is_some() {
         format!("{}/src", crate_dir)
     } else {
         crate_dir.clone()
     };
 
-    fs::create_dir_all(&src_dir).expect("Failed to create src directory");
+    let event_types = |uses: fn(&[TypeData], &[AccountData], &[InstructionData]) -> bool| {
+        events_data
+            .iter()
+            .any(|event| uses(slice::from_ref(&event.type_data), &[], &[]))
+    };
+    let needs_big_array = needs_big_array(&types_data, &accounts_data, &instructions_data)
+        || event_types(needs_big_array);
+    let uses_codec =
+        uses_codec(&types_data, &accounts_data, &instructions_data) || event_types(uses_codec);
 
-    let needs_big_array = types_data.iter().any(|type_data| {
-        type_data.fields.iter().any(|field| {
-            field.rust_type.starts_with("[")
-                && field.rust_type.ends_with("]")
-                && is_big_array(&field.rust_type)
-        })
-    });
+    let mut files = Vec::new();
 
     // Generate types
     let types_dir = format!("{}/types", src_dir);
-    fs::create_dir_all(&types_dir).expect("Failed to create types directory");
 
     for type_data in &types_data {
         let template = TypeStructTemplate { type_data };
         let rendered = template
             .render()
-            .expect("Failed to render type struct template");
+            .map_err(NitrogenError::template("type struct"))?;
         let filename = format!("{}/{}.rs", types_dir, type_data.name.to_snake_case());
-        fs::write(&filename, rendered).expect("Failed to write type struct file");
-        println!("Generated {}", filename);
+        files.push((filename, rendered));
     }
 
     let types_mod_content = types_data
@@ -97,98 +337,196 @@ pub fn parse(
                 type_data.name.to_snake_case()
             )
         })
+        .chain(
+            imported_types
+                .iter()
+                .map(|path| format!("pub use {};", path)),
+        )
         .collect::<Vec<_>>()
         .join("\n");
     let types_mod_filename = format!("{}/mod.rs", types_dir);
-    fs::write(&types_mod_filename, types_mod_content).expect("Failed to write types mod file");
-    println!("Generated {}", types_mod_filename);
+    files.push((types_mod_filename, types_mod_content));
 
     // Generate Accounts
 
     let accounts_dir = format!("{}/accounts", src_dir);
-    fs::create_dir_all(&accounts_dir).expect("Failed to create accounts directory");
 
     for account in &accounts_data {
         let template = AccountsStructTemplate { account };
         let rendered = template
             .render()
-            .expect("Failed to render account struct template");
+            .map_err(NitrogenError::template("account struct"))?;
         let filename = format!("{}/{}.rs", accounts_dir, account.module_name);
-        fs::write(&filename, rendered).expect("Failed to write account struct file");
-        println!("Generated {}", filename);
+        files.push((filename, rendered));
     }
 
     let accounts_mod_template = AccountsModTemplate {
         accounts: &accounts_data,
-        decoder_name: encoder_name.clone(),
         program_struct_name: program_struct_name.clone(),
     };
     let accounts_mod_rendered = accounts_mod_template
         .render()
-        .expect("Failed to render mod file");
+        .map_err(NitrogenError::template("accounts mod"))?;
     let accounts_mod_filename = format!("{}/mod.rs", accounts_dir);
 
-    fs::write(&accounts_mod_filename, accounts_mod_rendered)
-        .expect("Failed to write accounts mod file");
-    println!("Generated {}", accounts_mod_filename);
+    files.push((accounts_mod_filename, accounts_mod_rendered));
 
     // Generate Instructions
 
     let instructions_dir = format!("{}/instructions", src_dir);
-    fs::create_dir_all(&instructions_dir).expect("Failed to create instructions directory");
 
     for instruction in &instructions_data {
         let template = InstructionsStructTemplate { instruction };
         let rendered = template
             .render()
-            .expect("Failed to render instruction struct template");
+            .map_err(NitrogenError::template("instruction struct"))?;
         let filename = format!("{}/{}.rs", instructions_dir, instruction.module_name);
-        fs::write(&filename, rendered).expect("Failed to write instruction struct file");
-        println!("Generated {}", filename);
+        files.push((filename, rendered));
     }
 
     let instructions_mod_template = InstructionsModTemplate {
         instructions: &instructions_data,
         decoder_name: encoder_name.clone(),
         program_instruction_enum: program_instruction_enum.clone(),
+        requires_imports: instructions_data.iter().any(|i| i.requires_imports),
     };
     let instructions_mod_rendered = instructions_mod_template
         .render()
-        .expect("Failed to render instruction mod file");
+        .map_err(NitrogenError::template("instructions mod"))?;
     let instructions_mod_filename = format!("{}/mod.rs", instructions_dir);
 
-    fs::write(&instructions_mod_filename, instructions_mod_rendered)
-        .expect("Failed to write instructions mod file");
+    files.push((instructions_mod_filename, instructions_mod_rendered));
+
+    // Generate Errors
+
+    if !errors_data.is_empty() {
+        let errors_template = ErrorsTemplate {
+            errors: &errors_data,
+            error_enum,
+        };
+        let errors_rendered = errors_template
+            .render()
+            .map_err(NitrogenError::template("errors"))?;
+        let errors_filename = format!("{}/errors.rs", src_dir);
+        files.push((errors_filename, errors_rendered));
+    }
+
+    // Generate Events
+
+    if !events_data.is_empty() {
+        let events_dir = format!("{}/events", src_dir);
 
-    println!("Generated {}", instructions_mod_filename);
+        for event in &events_data {
+            let template = EventsStructTemplate { event };
+            let rendered = template
+                .render()
+                .map_err(NitrogenError::template("event struct"))?;
+            let filename = format!("{}/{}.rs", events_dir, event.module_name());
+            files.push((filename, rendered));
+        }
+
+        let events_mod_template = EventsModTemplate {
+            events: &events_data,
+            program_event_enum,
+        };
+        let events_mod_rendered = events_mod_template
+            .render()
+            .map_err(NitrogenError::template("events mod"))?;
+        let events_mod_filename = format!("{}/mod.rs", events_dir);
+        files.push((events_mod_filename, events_mod_rendered));
+    }
+
+    let mut modules = String::new();
+    if !errors_data.is_empty() {
+        modules.push_str("pub mod errors;\n");
+    }
+    if !events_data.is_empty() {
+        modules.push_str("pub mod events;\n");
+    }
     let crate_package_name = match &crate_name {
         Some(c) => c.clone(),
         None => format!("{program_name}-encoder").to_kebab_case(),
     };
 
     if crate_name.is_some() {
+        // Generate Client
+
+        let client_template = ClientTemplate {
+            accounts: &accounts_data,
+            client_name: format!("{}Client", program_name.to_upper_camel_case()),
+        };
+        let client_rendered = client_template
+            .render()
+            .map_err(NitrogenError::template("client"))?;
+        let client_filename = format!("{}/client.rs", src_dir);
+        files.push((client_filename, client_rendered));
+
         let lib_rs_content = format!(
-            r#"use {{solana_instruction::AccountMeta, solana_pubkey::declare_id}};
+            r#"use solana_pubkey::declare_id;
 pub struct {encoder_name};
 pub mod accounts;
 pub mod instructions;
 pub mod types;
-pub mod helpers;
-
+{modules}pub mod helpers;
+#[cfg(feature = "client")]
+pub mod client;
+{export_helpers}
 declare_id!("{program_id}");
 
 "#,
             encoder_name = encoder_name,
+            export_helpers = if overrides.export_helpers {
+                "pub use helpers::*;\n"
+            } else {
+                ""
+            },
             program_id = program_id
         );
         let lib_rs_filename = format!("{}/lib.rs", src_dir);
-        fs::write(&lib_rs_filename, lib_rs_content).expect("Failed to write lib.rs file");
-        println!("Generated {}", lib_rs_filename);
+        files.push((lib_rs_filename, lib_rs_content));
+
+        let mut dependencies = BTreeMap::from([
+            ("bon", "{ workspace = true, optional = true }".to_string()),
+            ("borsh", "{ workspace = true }".to_string()),
+            (
+                "nitrogen-instruction-builder",
+                "{ workspace = true }".to_string(),
+            ),
+            ("serde", "{ workspace = true, optional = true }".to_string()),
+            (
+                "solana-account-info",
+                "{ workspace = true, optional = true }".to_string(),
+            ),
+            (
+                "solana-cpi",
+                "{ workspace = true, optional = true }".to_string(),
+            ),
+            ("solana-instruction", "{ workspace = true }".to_string()),
+            (
+                "solana-program-error",
+                "{ workspace = true, optional = true }".to_string(),
+            ),
+            ("solana-pubkey", "{ workspace = true }".to_string()),
+        ]);
+        if needs_big_array {
+            dependencies.insert(
+                "serde-big-array",
+                "{ workspace = true, optional = true }".to_string(),
+            );
+        }
+        for dependency in &program_dependencies {
+            dependencies.insert(dependency, format!("{{ path = \"../{dependency}\" }}"));
+        }
+        for dependency in &overrides.dependencies {
+            dependencies
+                .entry(dependency)
+                .or_insert_with(|| "{ workspace = true }".to_string());
+        }
 
         let cargo_toml_content = format!(
             r#"[package]
 name = "{crate_package_name}"
-version = "0.1.0"
+version = "{version}"
 edition = {{ workspace = true }}
 description = "{crate_package_name}"
 license = {{ workspace = true }}
@@ -197,45 +535,312 @@ repository = {{ workspace = true }}
 keywords = ["solana", "idl"]
 categories = ["encoding"]
 
-[dependencies]
-bon = {{ workspace = true }}
-borsh = {{ workspace = true }}
-nitrogen-instruction-builder = {{ workspace = true }}
-serde = {{ workspace = true }}
-solana-instruction = {{ workspace = true }}
-solana-pubkey = {{ workspace = true }}
-{big_array}
+[features]
+default = ["builder", "serde"]
+builder = ["dep:bon", "nitrogen-instruction-builder/builder"]
+serde = ["dep:serde"{serde_features}]
+client = ["nitrogen-instruction-builder/client"]
+cpi = ["dep:solana-account-info", "dep:solana-cpi", "dep:solana-program-error"]
 
+[dependencies]
+{dependencies}
 [lints]
 workspace = true
 "#,
             crate_package_name = crate_package_name,
-            big_array = if needs_big_array {
-                "serde-big-array = { workspace = true }"
-            } else {
-                ""
-            }
+            version = crate_version(&crate_dir),
+            serde_features = serde_features(needs_big_array, human_readable || uses_codec)
+                + &program_dependencies
+                    .iter()
+                    .map(|dependency| format!(", \"{dependency}/serde\""))
+                    .collect::<String>(),
+            dependencies = dependencies
+                .iter()
+                .map(|(name, spec)| format!("{name} = {spec}\n"))
+                .collect::<String>(),
         );
         let cargo_toml_filename = format!("{}/Cargo.toml", crate_dir);
-        fs::write(&cargo_toml_filename, cargo_toml_content)
-            .expect("Failed to write Cargo.toml file");
-        println!("Generated {}", cargo_toml_filename);
+        files.push((cargo_toml_filename, cargo_toml_content));
     } else {
         let mod_rs_content = format!(
-            "pub struct {encoder_name};\npub mod accounts;\npub mod instructions;\npub mod types;",
+            "pub struct {encoder_name};\npub mod accounts;\npub mod instructions;\npub mod \
+             types;\n{modules}",
             encoder_name = encoder_name
         );
         let mod_rs_filename = format!("{}/mod.rs", src_dir);
-        fs::write(&mod_rs_filename, mod_rs_content).expect("Failed to write mod.rs file");
-        println!("Generated {}", mod_rs_filename);
+        files.push((mod_rs_filename, mod_rs_content));
+    }
+
+    // `Cargo.toml` is left unmarked, its version being bumped by hand.
+    for (filename, content) in &mut files {
+        if filename.ends_with(".rs") {
+            *content = format!(
+                "// {GENERATED_MARKER}, do not edit.\n{}",
+                format_generated_code(filename, content)?
+            );
+        }
     }
+
+    let stale_files = stale_generated_files(&crate_dir, &files);
+    let manifest = Manifest {
+        files: files
+            .iter()
+            .map(|(filename, _)| {
+                filename
+                    .strip_prefix(&format!("{crate_dir}/"))
+                    .unwrap_or(filename)
+                    .to_string()
+            })
+            .collect(),
+    };
+    files.push((
+        format!("{crate_dir}/{MANIFEST_FILENAME}"),
+        serde_json::to_string_pretty(&manifest)? + "\n",
+    ));
+
+    if check {
+        check_generated_files(&files, &stale_files)?;
+        return Ok(FileChanges::default());
+    }
+    let mut changes = FileChanges::default();
+    for (filename, content) in &files {
+        match fs::read_to_string(filename) {
+            Ok(existing) if existing == *content => changes.unchanged += 1,
+            existing => {
+                if let Some(dir) = Path::new(filename).parent() {
+                    fs::create_dir_all(dir)
+                        .map_err(NitrogenError::io("create", &dir.to_string_lossy()))?;
+                }
+                fs::write(filename, content).map_err(NitrogenError::io("write", filename))?;
+                match existing {
+                    Ok(_) => changes.changed.push(filename.clone()),
+                    Err(_) => changes.created.push(filename.clone()),
+                }
+            }
+        }
+        println!("Generated {}", filename);
+    }
+
+    for filename in stale_files {
+        fs::remove_file(&filename).map_err(NitrogenError::io("delete", &filename))?;
+        // Drops directories left empty, e.g. `events` once no event is
+        // generated.
+        if let Some(dir) = Path::new(&filename).parent() {
+            let _ = fs::remove_dir(dir);
+        }
+        println!("Deleted {}", filename);
+        changes.deleted.push(filename);
+    }
+
     let helper_filename = format!("{}/helpers.rs", src_dir);
     if !fs::exists(&helper_filename).ok().unwrap_or_default() {
         fs::write(
             &helper_filename,
             "// custom helper utilities for instructions",
         )
-        .expect("Failed to write helpers.rs file");
+        .map_err(NitrogenError::io("write", &helper_filename))?;
+        changes.created.push(helper_filename);
+    }
+    Ok(changes)
+}
+
+/// Version of the crate at `crate_dir`, kept across regenerations since
+/// releases bump it by hand.
+fn crate_version(crate_dir: &str) -> String {
+    #[derive(Deserialize)]
+    struct CargoToml {
+        package: CargoPackage,
+    }
+    #[derive(Deserialize)]
+    struct CargoPackage {
+        version: String,
+    }
+
+    fs::read_to_string(format!("{crate_dir}/Cargo.toml"))
+        .ok()
+        .and_then(|content| basic_toml::from_str::<CargoToml>(&content).ok())
+        .map_or_else(
+            || "0.1.0".to_string(),
+            |cargo_toml| cargo_toml.package.version,
+        )
+}
+
+/// Files listed in the manifest of `crate_dir` that aren't among `files`
+/// anymore. Files edited to drop the generated header are kept.
+fn stale_generated_files(crate_dir: &str, files: &[(String, String)]) -> Vec<String> {
+    let manifest_filename = format!("{crate_dir}/{MANIFEST_FILENAME}");
+    let Ok(manifest) = fs::read_to_string(&manifest_filename) else {
+        return Vec::new();
+    };
+    let Ok(manifest) = serde_json::from_str::<Manifest>(&manifest) else {
+        eprintln!("Warning: Ignoring unreadable {manifest_filename}");
+        return Vec::new();
+    };
+
+    manifest
+        .files
+        .into_iter()
+        .map(|filename| format!("{crate_dir}/{filename}"))
+        .filter(|filename| !files.iter().any(|(f, _)| f == filename))
+        .filter(|filename| match fs::read_to_string(filename) {
+            Ok(content) if is_generated(&content) => true,
+            Ok(_) => {
+                eprintln!("Warning: Keeping {filename}, no longer generated but edited");
+                false
+            }
+            Err(_) => false,
+        })
+        .collect()
+}
+
+fn is_generated(content: &str) -> bool {
+    content
+        .lines()
+        .next()
+        .is_some_and(|line| line.contains(GENERATED_MARKER))
+}
+
+/// `content` of `filename` formatted the way generated code is, so a file only
+/// reformatted since it was generated compares equal. Code that doesn't parse
+/// is left as is.
+fn normalized(filename: &str, content: &str) -> String {
+    if !filename.ends_with(".rs") {
+        return content.to_string();
+    }
+    // Comments are dropped by the formatting, so the header is kept apart.
+    let (header, code) = match content.split_once('\n') {
+        Some((header, code)) if header.contains(GENERATED_MARKER) => (header, code),
+        _ => ("", content),
+    };
+    match format_generated_code(filename, code) {
+        Ok(code) => format!("{header}\n{code}"),
+        Err(_) => content.to_string(),
+    }
+}
+
+/// Prints a unified diff of every generated file differing from the one on
+/// disk once both are formatted alike, and of every stale file, failing if
+/// there is any.
+fn check_generated_files(files: &[(String, String)], stale_files: &[String]) -> Result<()> {
+    let mut stale = stale_files.len();
+    for filename in stale_files {
+        let existing = fs::read_to_string(filename).unwrap_or_default();
+        print!(
+            "{}",
+            TextDiff::from_lines(existing.as_str(), "")
+                .unified_diff()
+                .header(filename, "/dev/null")
+        );
     }
+    for (filename, content) in files {
+        let existing = normalized(filename, &fs::read_to_string(filename).unwrap_or_default());
+        let content = normalized(filename, content);
+        if existing == content {
+            continue;
+        }
+        stale += 1;
+        print!(
+            "{}",
+            TextDiff::from_lines(&existing, &content)
+                .unified_diff()
+                .header(filename, filename)
+        );
+    }
+
+    if stale > 0 {
+        bail!("{stale} generated files are out of date");
+    }
+    println!("{} generated files are up to date", files.len());
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LEGACY_ESCROW: &str =
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../../idls/legacy_escrow.json");
+
+    #[test]
+    fn test_check_generated_files() {
+        let output = std::env::temp_dir().join(format!("nitrogen-check-{}", std::process::id()));
+        let output = output.to_str().unwrap().to_string();
+        let parse_escrow = |check| {
+            parse_legacy(
+                LEGACY_ESCROW.to_string(),
+                output.clone(),
+                None,
+                None,
+                None,
+                None,
+                false,
+                check,
+                &Overrides::default(),
+            )
+        };
+
+        assert!(parse_escrow(true).is_err());
+        assert!(!fs::exists(&output).unwrap());
+
+        parse_escrow(false).unwrap();
+        parse_escrow(true).unwrap();
+
+        let mod_rs = format!("{output}/escrow_encoder/mod.rs");
+        let reformatted = fs::read_to_string(&mod_rs).unwrap().replace(";\n", ";\n\n");
+        fs::write(&mod_rs, reformatted).unwrap();
+        parse_escrow(true).unwrap();
+
+        fs::write(&mod_rs, "").unwrap();
+        assert!(parse_escrow(true).is_err());
+
+        fs::remove_dir_all(&output).unwrap();
+    }
+
+    #[test]
+    fn test_check_committed_encoders() {
+        let config = concat!(env!("CARGO_MANIFEST_DIR"), "/../../nitrogen.toml");
+        crate::handlers::generate(config.to_string(), true).unwrap();
+    }
+
+    #[test]
+    fn test_stale_generated_files() {
+        let output = std::env::temp_dir().join(format!("nitrogen-stale-{}", std::process::id()));
+        let output = output.to_str().unwrap().to_string();
+        let parse_escrow = |filter: &str, check| {
+            parse_legacy(
+                LEGACY_ESCROW.to_string(),
+                output.clone(),
+                Some("escrow".to_string()),
+                Some(filter.to_string()),
+                None,
+                None,
+                false,
+                check,
+                &Overrides::default(),
+            )
+        };
+        let instruction = |name: &str| format!("{output}/escrow/src/instructions/{name}.rs");
+
+        let cargo_toml = format!("{output}/escrow/Cargo.toml");
+
+        parse_escrow("initialize,cancel,set_authority", false).unwrap();
+        fs::write(instruction("set_authority"), "// edited").unwrap();
+        let bumped = fs::read_to_string(&cargo_toml).unwrap().replacen(
+            "version = \"0.1.0\"",
+            "version = \"0.2.0\"",
+            1,
+        );
+        assert!(!is_generated(&bumped));
+        fs::write(&cargo_toml, &bumped).unwrap();
+
+        assert!(parse_escrow("initialize", true).is_err());
+        let changes = parse_escrow("initialize", false).unwrap();
+        assert_eq!(changes.deleted, vec![instruction("cancel")]);
+        assert!(fs::exists(instruction("set_authority")).unwrap());
+        assert!(fs::exists(format!("{output}/escrow/src/helpers.rs")).unwrap());
+        parse_escrow("initialize", true).unwrap();
+        assert_eq!(fs::read_to_string(&cargo_toml).unwrap(), bumped);
+
+        fs::remove_dir_all(&output).unwrap();
+    }
+}
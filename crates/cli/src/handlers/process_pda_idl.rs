@@ -1,5 +1,11 @@
 use {
-    crate::{commands::Url, handlers},
+    crate::{
+        commands::{IdlStandard, Url},
+        config::Overrides,
+        error::NitrogenError,
+        handlers::{self, FileChanges},
+        util::detect_idl_standard,
+    },
     anyhow::{Context, Result},
     borsh::BorshDeserialize,
     flate2::read::ZlibDecoder,
@@ -14,7 +20,13 @@ pub fn process_pda_idl(
     url: &Url,
     output: String,
     crate_name: Option<String>,
-) -> Result<()> {
+    filter: Option<String>,
+    account_filter: Option<String>,
+    event_filter: Option<String>,
+    human_readable: bool,
+    check: bool,
+    overrides: &Overrides,
+) -> Result<FileChanges> {
     let rpc_url = match url {
         Url::Mainnet => "https://api.mainnet-beta.solana.com",
         Url::Devnet => "https://api.devnet.solana.com",
@@ -29,22 +41,51 @@ pub fn process_pda_idl(
         program_address, rpc_url
     );
 
-    let idl = fetch_idl(program_address_pubkey, rpc_url.to_string())
-        .map(|idl| serde_json::to_string_pretty(&idl))
-        .context("Couldn't fetch Program Idl")??;
+    let mut idl = fetch_idl(program_address_pubkey, rpc_url.to_string())
+        .context("Couldn't fetch Program Idl")?;
+    // Legacy IDLs don't always carry the program address.
+    if idl.pointer("/metadata/spec").is_none()
+        && idl.pointer("/metadata/address").is_none()
+        && let Some(idl) = idl.as_object_mut()
+    {
+        let metadata = idl
+            .entry("metadata")
+            .or_insert_with(|| serde_json::json!({}));
+        if let Some(metadata) = metadata.as_object_mut() {
+            metadata.insert("address".to_string(), program_address.clone().into());
+        }
+    }
+    let idl = serde_json::to_string_pretty(&idl)?;
 
     let idl_path = format!("./{}_idl.json", program_address);
 
-    fs::write(&idl_path, idl)?;
+    fs::write(&idl_path, idl).map_err(NitrogenError::io("write", &idl_path))?;
 
-    handlers::parse(idl_path.clone(), output, crate_name, None).context("Couldn't parse IDL")?;
+    // IDLs without a 0.30 spec use the legacy layout.
+    let parse = match detect_idl_standard(&idl_path)? {
+        IdlStandard::AnchorLegacy => handlers::parse_legacy,
+        IdlStandard::Shank => handlers::parse_shank,
+        _ => handlers::parse,
+    };
+    let changes = parse(
+        idl_path.clone(),
+        output,
+        crate_name,
+        filter,
+        account_filter,
+        event_filter,
+        human_readable,
+        check,
+        overrides,
+    )
+    .context("Couldn't parse IDL")?;
 
     // Clean up: Delete the IDL file after parsing
     if Path::new(&idl_path).exists() {
         fs::remove_file(&idl_path).context("Failed to delete temporary IDL file")?;
     }
 
-    Ok(())
+    Ok(changes)
 }
 
 fn fetch_idl(program_address: Pubkey, rpc_url: String) -> Result<serde_json::Value> {
@@ -35,7 +35,7 @@ pub struct IdlMetadata {
 pub struct IdlInstruction {
     pub name: String,
     pub discriminator: Vec<u8>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub docs: Option<Vec<String>>,
     #[serde(default)]
     pub accounts: Vec<IdlInstructionAccount>,
@@ -46,17 +46,19 @@ pub struct IdlInstruction {
 #[derive(Debug, Serialize, Deserialize)]
 pub struct IdlInstructionAccount {
     pub name: String,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub writable: Option<bool>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub signer: Option<bool>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub optional: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub pda: Option<IdlPda>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub address: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub desc: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub docs: Option<Vec<String>>,
 }
 
@@ -68,11 +70,11 @@ pub struct IdlPda {
 #[derive(Debug, Serialize, Deserialize)]
 pub struct IdlPdaSeed {
     pub kind: String,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub value: Option<Vec<u8>>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub path: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub account: Option<String>,
 }
 
@@ -121,6 +123,7 @@ pub struct IdlAccount {
 pub struct IdlError {
     pub code: u32,
     pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub msg: Option<String>,
 }
 
@@ -134,9 +137,9 @@ pub struct IdlTypeDefinition {
 #[derive(Debug, Serialize)]
 pub struct IdlTypeDefinitionTy {
     pub kind: String,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub fields: Option<Vec<IdlEnumField>>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub variants: Option<Vec<IdlEnumVariant>>,
 }
 
@@ -193,12 +196,8 @@ impl<'de> Deserialize<'de> for IdlTypeDefinitionTy {
 
                 for f in fields {
                     match f {
-                        IdlEnumField::Tuple(type_) => {
-                            if is_primish(type_) {
-                                any_primish = true;
-                            } else {
-                                any_named = true;
-                            }
+                        IdlEnumField::Tuple(type_) if is_primish(type_) => {
+                            any_primish = true;
                         }
                         _ => {
                             any_named = true;
@@ -235,7 +234,7 @@ pub struct IdlTypeDefinitionField {
 #[derive(Debug, Serialize, Deserialize)]
 pub struct IdlEnumVariant {
     pub name: String,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub fields: Option<LegacyIdlEnumFields>,
 }
 
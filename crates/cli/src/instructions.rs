@@ -1,8 +1,9 @@
 use {
     crate::{
+        error::NitrogenError,
         idl::{Idl, IdlPda, IdlPdaSeed},
         legacy_idl::{LegacyIdl, LegacyIdlInstructionDiscriminant},
-        util::idl_type_to_rust_type,
+        util::{big_array_attribute, idl_type_to_rust_type, matches_filter},
     },
     askama::Template,
     heck::{ToSnakeCase, ToUpperCamelCase},
@@ -36,6 +37,16 @@ pub struct InstructionData {
     pub accounts: Vec<AccountMetaData>,
     pub param_types: HashSet<InstructionParamType>,
     pub requires_imports: bool,
+    /// Optional accounts left out are dropped from the account list rather
+    /// than replaced by the program id.
+    pub omit_optional_accounts: bool,
+}
+
+impl InstructionData {
+    /// Some account is derived from its seeds.
+    pub fn has_pdas(&self) -> bool {
+        self.accounts.iter().any(|account| account.pda.is_some())
+    }
 }
 
 #[allow(dead_code)]
@@ -43,6 +54,10 @@ pub struct InstructionData {
 pub struct ArgumentData {
     pub name: String,
     pub rust_type: String,
+    pub attributes: Option<String>,
+    /// Fixed bytes serialized in place of the argument, which is then left
+    /// out of the instruction struct.
+    pub constant: Option<Discriminator>,
 }
 
 #[derive(Debug, Hash, PartialEq, Eq)]
@@ -64,14 +79,46 @@ pub struct AccountMetaData {
     pub name: String,
     pub is_mut: bool,
     pub is_signer: bool,
+    /// The caller decides whether the account signs.
+    pub is_optional_signer: bool,
     pub is_optional: bool,
     pub address: Option<String>,
+    /// The fixed address is the program's own id.
+    pub is_program_id: bool,
     pub pda: Option<Pda>,
+    /// Rust expression for the account's pubkey when it defaults to another
+    /// account or an instruction argument.
+    pub default_value: Option<String>,
+}
+
+impl AccountMetaData {
+    /// The account's pubkey is passed by the caller.
+    pub fn is_param(&self) -> bool {
+        self.pda.is_none() && self.address.is_none() && self.default_value.is_none()
+    }
+
+    /// Type of the account parameter, paired with its signer flag when the
+    /// caller decides whether it signs.
+    pub fn param_type(&self) -> String {
+        let key = if self.is_optional_signer {
+            "(Pubkey, bool)"
+        } else {
+            "Pubkey"
+        };
+        if self.is_optional {
+            format!("Option<{key}>")
+        } else {
+            key.to_string()
+        }
+    }
 }
 
 #[derive(Debug)]
 pub struct Pda {
     pub seeds: Vec<PdaSeed>,
+    /// Program the PDA is derived from, when it isn't the instruction's
+    /// program.
+    pub program: Option<String>,
 }
 
 /// PdaParamType are used to build instructions from a parameter outside the
@@ -142,6 +189,7 @@ impl From<&IdlPda> for Pda {
     fn from(idl_pda: &IdlPda) -> Self {
         Pda {
             seeds: idl_pda.seeds.iter().map(PdaSeed::from).collect(),
+            program: None,
         }
     }
 }
@@ -153,18 +201,24 @@ pub struct PdaSeed {
     pub path: Option<String>,
     pub account: Option<String>,
     pub param_type: Option<PdaParamType>,
+    /// Rust expression of the seed bytes.
+    pub expr: Option<String>,
 }
 
 #[derive(Debug)]
 pub struct SeedData {
-    value: Vec<u8>,
+    pub value: Vec<u8>,
 }
 
 impl Display for SeedData {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let data = String::from_utf8_lossy(&self.value).to_string();
-        if data.is_ascii() {
-            write!(f, "b\"{}\"", data)
+        // Only printable bytes that need no escaping fit in a byte string.
+        if self
+            .value
+            .iter()
+            .all(|b| (b.is_ascii_graphic() || *b == b' ') && *b != b'"' && *b != b'\\')
+        {
+            write!(f, "b\"{}\"", String::from_utf8_lossy(&self.value))
         } else {
             let raw = self
                 .value
@@ -193,6 +247,7 @@ impl From<&IdlPdaSeed> for PdaSeed {
             path: idl_pda_seed.path.clone(),
             account: idl_pda_seed.account.clone(),
             param_type,
+            expr: None,
         }
     }
 }
@@ -209,19 +264,27 @@ pub struct InstructionsModTemplate<'a> {
     pub instructions: &'a Vec<InstructionData>,
     pub decoder_name: String,
     pub program_instruction_enum: String,
+    /// Some constructor argument is a defined type.
+    pub requires_imports: bool,
 }
 
-pub fn legacy_process_instructions(idl: &LegacyIdl) -> Vec<InstructionData> {
+pub fn legacy_process_instructions(
+    idl: &LegacyIdl,
+    filter: &[String],
+) -> Result<Vec<InstructionData>, NitrogenError> {
     let mut instructions_data = Vec::new();
 
     for instruction in &idl.instructions {
+        if !matches_filter(filter, &instruction.name) {
+            continue;
+        }
         let mut requires_imports = false;
         let module_name = instruction.name.to_snake_case();
         let struct_name = instruction.name.to_upper_camel_case();
         let discriminator = Discriminator(legacy_compute_instruction_discriminator(
             &instruction.name.to_snake_case(),
             instruction.discriminant.as_ref(),
-        ));
+        )?);
 
         let mut args = Vec::new();
         for arg in &instruction.args {
@@ -231,7 +294,9 @@ pub fn legacy_process_instructions(idl: &LegacyIdl) -> Vec<InstructionData> {
             }
             args.push(ArgumentData {
                 name: arg.name.to_snake_case(),
-                rust_type: rust_type.0,
+                rust_type: rust_type.0.clone(),
+                attributes: big_array_attribute(&rust_type.0),
+                constant: None,
             });
         }
 
@@ -241,8 +306,11 @@ pub fn legacy_process_instructions(idl: &LegacyIdl) -> Vec<InstructionData> {
                 name: account.name.to_snake_case(),
                 is_mut: account.is_mut,
                 is_signer: account.is_signer,
+                is_optional_signer: false,
                 is_optional: account.is_optional.unwrap_or(false),
                 address: None,
+                is_program_id: false,
+                default_value: None,
                 pda: None,
             });
         }
@@ -255,17 +323,18 @@ pub fn legacy_process_instructions(idl: &LegacyIdl) -> Vec<InstructionData> {
             accounts,
             param_types: HashSet::with_capacity(0),
             requires_imports,
+            omit_optional_accounts: false,
         });
     }
 
-    instructions_data
+    Ok(instructions_data)
 }
 
 pub fn process_instructions(idl: &Idl, filter: &[String]) -> Vec<InstructionData> {
     let mut instructions_data = Vec::new();
 
     for instruction in &idl.instructions {
-        if !filter.is_empty() && !filter.contains(&instruction.name) {
+        if !matches_filter(filter, &instruction.name) {
             continue;
         }
         let mut requires_imports = false;
@@ -281,7 +350,9 @@ pub fn process_instructions(idl: &Idl, filter: &[String]) -> Vec<InstructionData
             }
             args.push(ArgumentData {
                 name: arg.name.to_snake_case(),
-                rust_type: rust_type.0,
+                rust_type: rust_type.0.clone(),
+                attributes: big_array_attribute(&rust_type.0),
+                constant: None,
             });
         }
 
@@ -293,9 +364,10 @@ pub fn process_instructions(idl: &Idl, filter: &[String]) -> Vec<InstructionData
                 name: account.name.to_snake_case(),
                 is_mut: account.writable.unwrap_or(false),
                 is_signer: account.signer.unwrap_or(false),
-                // TODO: Check
-                is_optional: false,
+                is_optional_signer: false,
+                is_optional: account.optional.unwrap_or(false),
                 address: account.address.clone(),
+                is_program_id: account.address.as_ref() == Some(&idl.address),
                 pda: if let Some(pda) = &account.pda {
                     let p = Pda::from(pda);
                     for seed in &p.seeds {
@@ -309,6 +381,7 @@ pub fn process_instructions(idl: &Idl, filter: &[String]) -> Vec<InstructionData
                 } else {
                     None
                 },
+                default_value: None,
             });
         }
 
@@ -320,24 +393,37 @@ pub fn process_instructions(idl: &Idl, filter: &[String]) -> Vec<InstructionData
             accounts,
             param_types,
             requires_imports,
+            omit_optional_accounts: false,
         });
     }
 
     instructions_data
 }
 
-fn legacy_compute_instruction_discriminator(
+pub fn legacy_compute_instruction_discriminator(
     instruction_name: &str,
     option_discriminant: Option<&LegacyIdlInstructionDiscriminant>,
-) -> Vec<u8> {
+) -> Result<Vec<u8>, NitrogenError> {
     if let Some(discriminant) = option_discriminant {
-        discriminant.value.to_be_bytes().to_vec()
+        let width = match discriminant.type_.as_str() {
+            "u8" => 1,
+            "u16" => 2,
+            "u32" => 4,
+            "u64" => 8,
+            other => {
+                return Err(NitrogenError::Unsupported {
+                    item: instruction_name.to_string(),
+                    construct: format!("discriminant type `{other}`"),
+                });
+            }
+        };
+        Ok(discriminant.value.to_le_bytes()[..width].to_vec())
     } else {
         let mut hasher = Sha256::new();
         let discriminator_input = format!("global:{}", instruction_name);
         hasher.update(discriminator_input.as_bytes());
         let hash = hasher.finalize();
-        hash[..8].to_vec()
+        Ok(hash[..8].to_vec())
     }
 }
 
@@ -366,4 +452,95 @@ mod tests {
         set.insert(pt.clone());
         assert_eq!(1, set.len());
     }
+
+    #[test]
+    fn test_legacy_process_instructions() {
+        let idl = crate::util::legacy_read_idl(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../idls/legacy_escrow.json"
+        ))
+        .unwrap();
+        let instructions =
+            legacy_process_instructions(&idl, &["set_authority".to_string()]).unwrap();
+        assert_eq!(instructions.len(), 1);
+        assert_eq!(instructions[0].module_name, "set_authority");
+        assert_eq!(instructions[0].args[0].name, "new_authority");
+
+        let instructions = legacy_process_instructions(&idl, &[]).unwrap();
+        assert_eq!(instructions[0].discriminator.0, vec![
+            175, 175, 109, 31, 13, 152, 155, 237
+        ]);
+        assert!(instructions[0].accounts[2].is_optional);
+    }
+
+    #[test]
+    fn test_optional_account_placeholder() {
+        let idl = crate::util::legacy_read_idl(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../idls/legacy_escrow.json"
+        ))
+        .unwrap();
+        let instructions = legacy_process_instructions(&idl, &["initialize".to_string()]).unwrap();
+        let rendered = InstructionsStructTemplate {
+            instruction: &instructions[0],
+        }
+        .render()
+        .unwrap();
+        let code = crate::util::format_generated_code("initialize.rs", &rendered).unwrap();
+
+        // `referrer: None` keeps its slot so `system_program` stays fourth.
+        let builder = &code[code.find("let mut accounts").unwrap()..];
+        let pushes: Vec<&str> = builder
+            .lines()
+            .take_while(|line| !line.contains("InstructionBuilder::new"))
+            .map(str::trim)
+            .filter(|line| line.starts_with("accounts.push"))
+            .collect();
+        assert_eq!(pushes, vec![
+            "accounts.push(AccountMeta::new(maker, true));",
+            "accounts.push(AccountMeta::new(escrow, false));",
+            "accounts.push(AccountMeta::new_readonly(pk, false));",
+            "accounts.push(AccountMeta::new_readonly(__program_id, false));",
+            "accounts.push(AccountMeta::new_readonly(system_program, false));",
+        ]);
+        assert!(
+            code.contains("accounts.push(AccountMeta::new_readonly(*self.__program.key, false));")
+        );
+    }
+
+    #[test]
+    fn test_program_id_account_name() {
+        let idl = crate::util::legacy_read_idl(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../idls/legacy_escrow.json"
+        ))
+        .unwrap();
+        let mut instructions =
+            legacy_process_instructions(&idl, &["initialize".to_string()]).unwrap();
+        instructions[0].accounts[1].name = "program_id".to_string();
+        let rendered = InstructionsStructTemplate {
+            instruction: &instructions[0],
+        }
+        .render()
+        .unwrap();
+        let code = crate::util::format_generated_code("initialize.rs", &rendered).unwrap();
+
+        // The account keeps its name next to the deployment's address.
+        assert!(code.contains("__program_id: Pubkey,"));
+        assert!(code.contains("program_id: Pubkey,"));
+        assert!(code.contains("accounts.push(AccountMeta::new(program_id, false));"));
+        assert!(code.contains("InstructionBuilder::new(__program_id, accounts, self)"));
+    }
+
+    #[test]
+    fn test_shank_discriminants() {
+        let idl = crate::util::legacy_read_idl(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../idls/shank_vault.json"
+        ))
+        .unwrap();
+        let instructions = legacy_process_instructions(&idl, &[]).unwrap();
+        assert_eq!(instructions[1].discriminator.0, vec![1]);
+        assert_eq!(instructions[2].discriminator.0, vec![2, 1, 0, 0]);
+    }
 }
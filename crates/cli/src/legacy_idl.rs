@@ -17,6 +17,15 @@ pub struct LegacyIdl {
     pub events: Vec<LegacyIdlEvent>,
     #[serde(default)]
     pub errors: Vec<LegacyIdlError>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<LegacyIdlMetadata>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LegacyIdlMetadata {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -33,7 +42,7 @@ pub struct LegacyIdlConst {
 pub struct LegacyIdlInstruction {
     pub name: String,
     pub discriminant: Option<LegacyIdlInstructionDiscriminant>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub docs: Option<Vec<String>>,
     #[serde(default)]
     pub accounts: Vec<LegacyIdlInstructionAccount>,
@@ -46,7 +55,7 @@ pub struct LegacyIdlInstruction {
 pub struct LegacyIdlInstructionDiscriminant {
     #[serde(rename = "type")]
     pub type_: String,
-    pub value: u8,
+    pub value: u64,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -57,11 +66,16 @@ pub struct LegacyIdlInstructionAccount {
     pub is_mut: bool,
     #[serde(default)]
     pub is_signer: bool,
-    #[serde(default, alias = "optional", alias = "IsOptional")]
+    #[serde(
+        default,
+        alias = "optional",
+        alias = "IsOptional",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub is_optional: Option<bool>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub docs: Option<Vec<String>>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub desc: Option<String>,
 }
 
@@ -71,7 +85,7 @@ pub struct LegacyIdlInstructionArgField {
     pub name: String,
     #[serde(rename = "type")]
     pub type_: LegacyIdlType,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub docs: Option<Vec<String>>,
 }
 
@@ -112,7 +126,7 @@ pub struct LegacyIdlAccountItem {
     pub name: String,
     #[serde(rename = "type")]
     pub type_: LegacyIdlAccountType,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub docs: Option<Vec<String>>,
 }
 
@@ -120,9 +134,9 @@ pub struct LegacyIdlAccountItem {
 #[serde(rename_all = "camelCase")]
 pub struct LegacyIdlAccountType {
     pub kind: String,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub fields: Option<Vec<LegacyIdlTypeDefinitionField>>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub variants: Option<Vec<LegacyIdlEnumVariant>>,
 }
 
@@ -132,7 +146,7 @@ pub struct LegacyIdlTypeDefinition {
     pub name: String,
     #[serde(rename = "type")]
     pub type_: LegacyIdlTypeDefinitionTy,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub docs: Option<Vec<String>>,
 }
 
@@ -140,9 +154,9 @@ pub struct LegacyIdlTypeDefinition {
 #[serde(rename_all = "camelCase")]
 pub struct LegacyIdlTypeDefinitionTy {
     pub kind: String,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub fields: Option<Vec<LegacyIdlEnumField>>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub variants: Option<Vec<LegacyIdlEnumVariant>>,
 }
 
@@ -152,7 +166,7 @@ pub struct LegacyIdlTypeDefinitionField {
     pub name: String,
     #[serde(rename = "type")]
     pub type_: LegacyIdlType,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub docs: Option<Vec<String>>,
 }
 
@@ -160,7 +174,7 @@ pub struct LegacyIdlTypeDefinitionField {
 #[serde(rename_all = "camelCase")]
 pub struct LegacyIdlEnumVariant {
     pub name: String,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub fields: Option<LegacyIdlEnumFields>,
 }
 
@@ -1,54 +1,57 @@
 use {
     clap::Parser,
-    commands::{Cli, Commands, IdlSource, IdlStandard},
+    commands::{Cli, Commands, IdlCommands},
+    config::Overrides,
+    error::NitrogenError,
+    std::process::ExitCode,
 };
 
 pub mod accounts;
 pub mod commands;
+pub mod config;
+pub mod error;
+pub mod events;
 pub mod handlers;
 pub mod idl;
 pub mod instructions;
 mod legacy_idl;
+pub mod program_errors;
 pub mod types;
 pub mod util;
 
-fn main() -> anyhow::Result<()> {
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {e:#}");
+            let code = e
+                .chain()
+                .find_map(|e| e.downcast_ref::<NitrogenError>())
+                .map_or(1, NitrogenError::exit_code);
+            ExitCode::from(code)
+        }
+    }
+}
+
+fn run() -> anyhow::Result<()> {
     let cli = Cli::parse();
 
     match cli.command {
-        Commands::Parse(options) => match options.idl {
-            IdlSource::FilePath(path) => match options.standard {
-                IdlStandard::Codama => {
-                    handlers::parse_codama(
-                        path,
-                        options.output,
-                        options.crate_name,
-                        options.event_hints,
-                    )?;
-                }
-                IdlStandard::Anchor => {
-                    if options.event_hints.is_some() {
-                        anyhow::bail!("The '--event-hints' option can only be used with --codama.");
-                    }
-                    handlers::parse(path, options.output, options.crate_name, options.filter)?;
-                }
-            },
-            IdlSource::ProgramAddress(program_address) => {
-                let url = options.url.as_ref().ok_or_else(|| {
-                    anyhow::anyhow!(
-                        "Network URL (--url / -u) argument is required when parsing an IDL from a \
-                         program address."
-                    )
-                })?;
-
-                handlers::process_pda_idl(
-                    program_address,
-                    url,
-                    options.output,
-                    options.crate_name,
-                )?;
-            }
-        },
+        Commands::Parse(options) => {
+            handlers::parse_idl(options, &Overrides::default())?;
+        }
+        Commands::Generate(options) => {
+            handlers::generate(options.config, options.check)?;
+        }
+        Commands::Inspect(options) => {
+            handlers::inspect(options.idl, options.standard, options.json)?;
+        }
+        Commands::Idl(IdlCommands::Convert(options)) => {
+            handlers::convert_idl(options.idl, options.output, options.address)?;
+        }
+        Commands::Idl(IdlCommands::Diff(options)) => {
+            handlers::idl_diff(options.old, options.new, options.json)?;
+        }
     }
 
     Ok(())
@@ -0,0 +1,22 @@
+use askama::Template;
+
+#[derive(Debug)]
+pub struct ErrorData {
+    pub name: String,
+    pub code: u32,
+    pub message: String,
+}
+
+impl ErrorData {
+    /// The message as a Rust string literal.
+    pub fn message_literal(&self) -> String {
+        format!("{:?}", self.message)
+    }
+}
+
+#[derive(Template)]
+#[template(path = "errors.askama", escape = "none", ext = ".askama")]
+pub struct ErrorsTemplate<'a> {
+    pub errors: &'a Vec<ErrorData>,
+    pub error_enum: String,
+}
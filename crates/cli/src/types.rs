@@ -1,15 +1,17 @@
 use {
     crate::{
+        error::NitrogenError,
         idl::{Idl, IdlEnumField},
-        legacy_idl::LegacyIdlEnumFields,
-        util::{idl_type_to_rust_type, is_big_array},
+        legacy_idl::{LegacyIdl, LegacyIdlEnumField, LegacyIdlEnumFields, LegacyIdlType},
+        util::{big_array_attribute, idl_type_to_rust_type, matches_filter, visit_defined_types},
     },
     askama::Template,
     heck::ToSnakeCase,
+    std::collections::HashSet,
 };
 
 #[allow(dead_code)]
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct TypeData {
     pub name: String,
     pub fields: Vec<FieldData>,
@@ -18,7 +20,7 @@ pub struct TypeData {
 }
 
 #[allow(dead_code)]
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum TypeKind {
     Struct,
     TupleStruct,
@@ -26,7 +28,7 @@ pub enum TypeKind {
 }
 
 #[allow(dead_code)]
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct FieldData {
     pub name: String,
     pub rust_type: String,
@@ -35,17 +37,17 @@ pub struct FieldData {
 }
 
 #[allow(dead_code)]
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct EnumVariantData {
     pub name: String,
     pub fields: Option<EnumVariantFields>,
 }
 
 #[allow(dead_code)]
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum EnumVariantFields {
     Named(Vec<FieldData>),
-    Unnamed(Vec<String>),
+    Unnamed(Vec<FieldData>),
 }
 
 #[derive(Template)]
@@ -54,167 +56,205 @@ pub struct TypeStructTemplate<'a> {
     pub type_data: &'a TypeData,
 }
 
-pub fn process_types(idl: &Idl) -> Vec<TypeData> {
+/// Names of the types reachable from the selected instructions, accounts
+/// and events, or `None` when nothing is filtered. Event types are only kept
+/// when named in `event_filter`.
+pub fn reachable_types(
+    idl: &Idl,
+    instruction_filter: &[String],
+    account_filter: &[String],
+    event_filter: &[String],
+) -> Option<HashSet<String>> {
+    if instruction_filter.is_empty() && account_filter.is_empty() && event_filter.is_empty() {
+        return None;
+    }
+
+    let mut roots = Vec::new();
+    for instruction in &idl.instructions {
+        if matches_filter(instruction_filter, &instruction.name) {
+            roots.extend(instruction.args.iter().map(|arg| &arg.type_));
+        }
+    }
+    let mut pending: Vec<String> = idl
+        .accounts
+        .iter()
+        .filter(|account| matches_filter(account_filter, &account.name))
+        .map(|account| account.name.clone())
+        .chain(
+            idl.events
+                .iter()
+                .filter(|event| {
+                    !event_filter.is_empty() && matches_filter(event_filter, &event.name)
+                })
+                .map(|event| event.name.clone()),
+        )
+        .collect();
+    for root in roots {
+        visit_defined_types(root, &mut |name| pending.push(name.to_string()));
+    }
+
+    Some(close_over_types(pending, |name| {
+        let Some(idl_type_def) = idl.types.iter().find(|ty| ty.name == name) else {
+            return Vec::new();
+        };
+        let fields = idl_type_def
+            .type_
+            .fields
+            .iter()
+            .flatten()
+            .map(|field| match field {
+                IdlEnumField::Named(field) => &field.type_,
+                IdlEnumField::Tuple(field) => field,
+            });
+        let variants = idl_type_def.type_.variants.iter().flatten();
+        fields
+            .chain(variants.flat_map(|variant| variant_field_types(variant.fields.as_ref())))
+            .collect()
+    }))
+}
+
+/// [`reachable_types`] for legacy IDLs, where account types are declared
+/// with the accounts.
+pub fn legacy_reachable_types(
+    idl: &LegacyIdl,
+    instruction_filter: &[String],
+    account_filter: &[String],
+    event_filter: &[String],
+) -> Option<HashSet<String>> {
+    if instruction_filter.is_empty() && account_filter.is_empty() && event_filter.is_empty() {
+        return None;
+    }
+
+    let mut roots = Vec::new();
+    for instruction in &idl.instructions {
+        if matches_filter(instruction_filter, &instruction.name) {
+            roots.extend(instruction.args.iter().map(|arg| &arg.type_));
+        }
+    }
+    let mut pending: Vec<String> = idl
+        .accounts
+        .iter()
+        .filter(|account| matches_filter(account_filter, &account.name))
+        .map(|account| account.name.clone())
+        .collect();
+    for event in &idl.events {
+        if !event_filter.is_empty() && matches_filter(event_filter, &event.name) {
+            roots.extend(event.fields.iter().map(|field| &field.type_));
+        }
+    }
+    for root in roots {
+        visit_defined_types(root, &mut |name| pending.push(name.to_string()));
+    }
+
+    Some(close_over_types(pending, |name| {
+        if let Some(account) = idl.accounts.iter().find(|account| account.name == name) {
+            let fields = account.type_.fields.iter().flatten();
+            let variants = account.type_.variants.iter().flatten();
+            return fields
+                .map(|field| &field.type_)
+                .chain(variants.flat_map(|variant| variant_field_types(variant.fields.as_ref())))
+                .collect();
+        }
+        let Some(idl_type_def) = idl.types.iter().find(|ty| ty.name == name) else {
+            return Vec::new();
+        };
+        let fields = idl_type_def
+            .type_
+            .fields
+            .iter()
+            .flatten()
+            .map(|field| match field {
+                LegacyIdlEnumField::Named(field) => &field.type_,
+                LegacyIdlEnumField::Tuple(field) => field,
+            });
+        let variants = idl_type_def.type_.variants.iter().flatten();
+        fields
+            .chain(variants.flat_map(|variant| variant_field_types(variant.fields.as_ref())))
+            .collect()
+    }))
+}
+
+/// Names of `pending` and of every type they reference, following the field
+/// types returned by `field_types`.
+fn close_over_types<'a>(
+    mut pending: Vec<String>,
+    field_types: impl Fn(&str) -> Vec<&'a LegacyIdlType>,
+) -> HashSet<String> {
+    let mut reachable = HashSet::new();
+    while let Some(name) = pending.pop() {
+        if !reachable.insert(name.clone()) {
+            continue;
+        }
+        for field_type in field_types(&name) {
+            visit_defined_types(field_type, &mut |name| pending.push(name.to_string()));
+        }
+    }
+    reachable
+}
+
+fn variant_field_types(fields: Option<&LegacyIdlEnumFields>) -> Vec<&LegacyIdlType> {
+    match fields {
+        Some(LegacyIdlEnumFields::Named(fields)) => {
+            fields.iter().map(|field| &field.type_).collect()
+        }
+        Some(LegacyIdlEnumFields::Tuple(fields)) => fields.iter().collect(),
+        None => Vec::new(),
+    }
+}
+
+pub fn process_types(
+    idl: &Idl,
+    reachable: Option<&HashSet<String>>,
+) -> Result<Vec<TypeData>, NitrogenError> {
     let mut types_data = Vec::new();
 
     for idl_type_def in &idl.types {
+        if reachable.is_some_and(|reachable| !reachable.contains(&idl_type_def.name)) {
+            continue;
+        }
         let mut requires_imports = false;
         let name = idl_type_def.name.clone();
         let mut fields = Vec::new();
         let mut kind = TypeKind::Struct;
 
         match idl_type_def.type_.kind.as_str() {
-            "tuple_struct" => {
-                kind = TypeKind::TupleStruct;
-                if let Some(ref fields_vec) = idl_type_def.type_.fields {
-                    for field in fields_vec {
-                        match field {
-                            IdlEnumField::Named(field) => {
-                                let rust_type = idl_type_to_rust_type(&field.type_);
-                                if rust_type.1 {
-                                    requires_imports = true;
-                                }
-                                let is_pubkey = rust_type.0.contains("Pubkey");
-                                let attributes = if is_big_array(&rust_type.0) {
-                                    Some(
-                                        "#[serde(with = \"serde_big_array::BigArray\")]"
-                                            .to_string(),
-                                    )
-                                } else {
-                                    None
-                                };
-                                fields.push(FieldData {
-                                    name: field.name.to_snake_case(),
-                                    rust_type: rust_type.0,
-                                    is_pubkey,
-                                    attributes,
-                                });
-                            }
-                            IdlEnumField::Tuple(field) => {
-                                let rust_type = idl_type_to_rust_type(field);
-                                if rust_type.1 {
-                                    requires_imports = true;
-                                }
-                                let is_pubkey = rust_type.0.contains("Pubkey");
-                                let attributes = if is_big_array(&rust_type.0) {
-                                    Some(
-                                        "#[serde(with = \"serde_big_array::BigArray\")]"
-                                            .to_string(),
-                                    )
-                                } else {
-                                    None
-                                };
-                                fields.push(FieldData {
-                                    name: rust_type.0.to_snake_case(),
-                                    rust_type: rust_type.0,
-                                    is_pubkey,
-                                    attributes,
-                                });
-                            }
-                        };
-                    }
+            "tuple_struct" | "struct" => {
+                if idl_type_def.type_.kind == "tuple_struct" {
+                    kind = TypeKind::TupleStruct;
                 }
-            }
-            "struct" => {
-                if let Some(ref fields_vec) = idl_type_def.type_.fields {
-                    for field in fields_vec {
-                        match field {
-                            IdlEnumField::Named(field) => {
-                                let rust_type = idl_type_to_rust_type(&field.type_);
-                                if rust_type.1 {
-                                    requires_imports = true;
-                                }
-                                let is_pubkey = rust_type.0.contains("Pubkey");
-                                let attributes = if is_big_array(&rust_type.0) {
-                                    Some(
-                                        "#[serde(with = \"serde_big_array::BigArray\")]"
-                                            .to_string(),
-                                    )
-                                } else {
-                                    None
-                                };
-                                fields.push(FieldData {
-                                    name: field.name.to_snake_case(),
-                                    rust_type: rust_type.0,
-                                    is_pubkey,
-                                    attributes,
-                                });
-                            }
-                            IdlEnumField::Tuple(field) => {
-                                let rust_type = idl_type_to_rust_type(field);
-                                if rust_type.1 {
-                                    requires_imports = true;
-                                }
-                                let is_pubkey = rust_type.0.contains("Pubkey");
-                                let attributes = if is_big_array(&rust_type.0) {
-                                    Some(
-                                        "#[serde(with = \"serde_big_array::BigArray\")]"
-                                            .to_string(),
-                                    )
-                                } else {
-                                    None
-                                };
-                                fields.push(FieldData {
-                                    name: rust_type.0.to_snake_case(),
-                                    rust_type: rust_type.0,
-                                    is_pubkey,
-                                    attributes,
-                                });
-                            }
-                        };
-                    }
+                for field in idl_type_def.type_.fields.iter().flatten() {
+                    fields.push(match field {
+                        IdlEnumField::Named(field) => {
+                            process_field(Some(&field.name), &field.type_, &mut requires_imports)
+                        }
+                        IdlEnumField::Tuple(field) => {
+                            process_field(None, field, &mut requires_imports)
+                        }
+                    });
                 }
             }
             "enum" => {
-                let mut variants = Vec::new();
-                if let Some(ref variants_vec) = idl_type_def.type_.variants {
-                    for variant in variants_vec {
-                        let variant_name = variant.name.clone();
-                        let variant_fields = if let Some(ref fields) = variant.fields {
-                            match fields {
-                                LegacyIdlEnumFields::Named(named_fields) => {
-                                    let mut variant_field_data = Vec::new();
-                                    for field in named_fields {
-                                        let rust_type = idl_type_to_rust_type(&field.type_);
-                                        if rust_type.1 {
-                                            requires_imports = true;
-                                        }
-                                        let is_pubkey = rust_type.0.contains("Pubkey");
-                                        variant_field_data.push(FieldData {
-                                            name: field.name.to_snake_case(),
-                                            rust_type: rust_type.0,
-                                            is_pubkey,
-                                            attributes: None,
-                                        });
-                                    }
-                                    Some(EnumVariantFields::Named(variant_field_data))
-                                }
-                                LegacyIdlEnumFields::Tuple(tuple_fields) => {
-                                    let rust_types = tuple_fields
-                                        .iter()
-                                        .map(|ty| {
-                                            let rust_type = idl_type_to_rust_type(ty);
-                                            if rust_type.1 {
-                                                requires_imports = true;
-                                            }
-                                            rust_type.0
-                                        })
-                                        .collect();
-                                    Some(EnumVariantFields::Unnamed(rust_types))
-                                }
-                            }
-                        } else {
-                            None
-                        };
-                        variants.push(EnumVariantData {
-                            name: variant_name,
-                            fields: variant_fields,
-                        });
-                    }
-                }
+                let variants = idl_type_def
+                    .type_
+                    .variants
+                    .iter()
+                    .flatten()
+                    .map(|variant| {
+                        process_variant(
+                            &variant.name,
+                            variant.fields.as_ref(),
+                            &mut requires_imports,
+                        )
+                    })
+                    .collect();
                 kind = TypeKind::Enum(variants);
             }
-            _ => {}
+            other => {
+                return Err(NitrogenError::Unsupported {
+                    item: name,
+                    construct: format!("type kind `{other}`"),
+                });
+            }
         }
 
         types_data.push(TypeData {
@@ -225,5 +265,205 @@ pub fn process_types(idl: &Idl) -> Vec<TypeData> {
         });
     }
 
+    Ok(types_data)
+}
+
+/// Types of a legacy IDL, account types included.
+pub fn legacy_process_types(idl: &LegacyIdl, reachable: Option<&HashSet<String>>) -> Vec<TypeData> {
+    let mut types_data = Vec::new();
+
+    let account_types = idl.accounts.iter().map(|account| {
+        let fields = account
+            .type_
+            .fields
+            .iter()
+            .flatten()
+            .map(|field| (Some(&field.name), &field.type_))
+            .collect::<Vec<_>>();
+        (
+            &account.name,
+            &account.type_.kind,
+            fields,
+            &account.type_.variants,
+        )
+    });
+    let defined_types = idl.types.iter().map(|idl_type_def| {
+        let fields = idl_type_def
+            .type_
+            .fields
+            .iter()
+            .flatten()
+            .map(|field| match field {
+                LegacyIdlEnumField::Named(field) => (Some(&field.name), &field.type_),
+                LegacyIdlEnumField::Tuple(field) => (None, field),
+            })
+            .collect::<Vec<_>>();
+        (
+            &idl_type_def.name,
+            &idl_type_def.type_.kind,
+            fields,
+            &idl_type_def.type_.variants,
+        )
+    });
+
+    for (name, kind, idl_fields, variants) in account_types.chain(defined_types) {
+        if reachable.is_some_and(|reachable| !reachable.contains(name)) {
+            continue;
+        }
+        let mut requires_imports = false;
+        let fields = idl_fields
+            .iter()
+            .map(|(field_name, field_type)| {
+                process_field(
+                    field_name.map(String::as_str),
+                    field_type,
+                    &mut requires_imports,
+                )
+            })
+            .collect::<Vec<_>>();
+        let kind = match kind.as_str() {
+            "enum" => TypeKind::Enum(
+                variants
+                    .iter()
+                    .flatten()
+                    .map(|variant| {
+                        process_variant(
+                            &variant.name,
+                            variant.fields.as_ref(),
+                            &mut requires_imports,
+                        )
+                    })
+                    .collect(),
+            ),
+            _ if !fields.is_empty() && idl_fields.iter().all(|(name, _)| name.is_none()) => {
+                TypeKind::TupleStruct
+            }
+            _ => TypeKind::Struct,
+        };
+
+        types_data.push(TypeData {
+            name: name.clone(),
+            fields,
+            kind,
+            requires_imports,
+        });
+    }
+
     types_data
 }
+
+/// Struct field, named after its type when it's a tuple field.
+fn process_field(
+    name: Option<&str>,
+    idl_type: &LegacyIdlType,
+    requires_imports: &mut bool,
+) -> FieldData {
+    let rust_type = idl_type_to_rust_type(idl_type);
+    if rust_type.1 {
+        *requires_imports = true;
+    }
+    FieldData {
+        name: name.unwrap_or(&rust_type.0).to_snake_case(),
+        is_pubkey: rust_type.0.contains("Pubkey"),
+        attributes: big_array_attribute(&rust_type.0),
+        rust_type: rust_type.0,
+    }
+}
+
+fn process_variant(
+    name: &str,
+    fields: Option<&LegacyIdlEnumFields>,
+    requires_imports: &mut bool,
+) -> EnumVariantData {
+    let fields = fields.map(|fields| match fields {
+        LegacyIdlEnumFields::Named(named_fields) => EnumVariantFields::Named(
+            named_fields
+                .iter()
+                .map(|field| FieldData {
+                    attributes: None,
+                    ..process_field(Some(&field.name), &field.type_, requires_imports)
+                })
+                .collect(),
+        ),
+        LegacyIdlEnumFields::Tuple(tuple_fields) => EnumVariantFields::Unnamed(
+            tuple_fields
+                .iter()
+                .map(|ty| FieldData {
+                    attributes: None,
+                    ..process_field(None, ty, requires_imports)
+                })
+                .collect(),
+        ),
+    });
+    EnumVariantData {
+        name: name.to_string(),
+        fields,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::util::read_idl};
+
+    #[test]
+    fn test_unsupported_type_kind() {
+        let idl: Idl = serde_json::from_value(serde_json::json!({
+            "address": "Escrow1111111111111111111111111111111111111",
+            "metadata": { "name": "escrow", "version": "0.1.0", "spec": "0.1.0", "description": "" },
+            "types": [{ "name": "Amount", "type": { "kind": "type", "alias": "u64" } }]
+        }))
+        .unwrap();
+        let Err(NitrogenError::Unsupported { item, construct }) = process_types(&idl, None) else {
+            panic!("expected an unsupported type kind");
+        };
+        assert_eq!(item, "Amount");
+        assert_eq!(construct, "type kind `type`");
+    }
+
+    #[test]
+    fn test_reachable_types() {
+        let idl = read_idl(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../idls/token_messenger_minter_v2.json"
+        ))
+        .unwrap();
+        assert!(reachable_types(&idl, &[], &[], &[]).is_none());
+
+        let filter = ["deposit_for_burn".to_string()];
+        let reachable = reachable_types(&idl, &filter, &["TokenPair".to_string()], &[]).unwrap();
+        assert!(reachable.contains("DepositForBurnParams"));
+        assert!(reachable.contains("TokenPair"));
+        assert!(!reachable.contains("LocalToken"));
+        assert!(!reachable.contains("PauseParams"));
+        assert!(!reachable.contains("DepositForBurn"));
+
+        let reachable =
+            reachable_types(&idl, &filter, &[], &["DepositForBurn".to_string()]).unwrap();
+        assert!(reachable.contains("DepositForBurn"));
+        assert!(reachable.contains("LocalToken"));
+    }
+
+    #[test]
+    fn test_legacy_process_types() {
+        let idl = crate::util::legacy_read_idl(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../idls/legacy_escrow.json"
+        ))
+        .unwrap();
+        let types = legacy_process_types(&idl, None);
+        assert_eq!(types[0].name, "Escrow");
+        assert_eq!(types[0].fields[2].rust_type, "EscrowTerms");
+        let TypeKind::Enum(variants) = &types[2].kind else {
+            panic!("EscrowStatus is an enum");
+        };
+        let Some(EnumVariantFields::Unnamed(fields)) = &variants[2].fields else {
+            panic!("EscrowStatus::{} is a tuple variant", variants[2].name);
+        };
+        assert_eq!(fields[0].rust_type, "Vec<u8>");
+
+        let reachable = legacy_reachable_types(&idl, &["cancel".to_string()], &[], &[]).unwrap();
+        let types = legacy_process_types(&idl, Some(&reachable));
+        let names: Vec<_> = types.iter().map(|ty| ty.name.as_str()).collect();
+        assert_eq!(names, vec!["Escrow", "EscrowTerms", "EscrowStatus"]);
+    }
+}
@@ -1,33 +1,140 @@
 use {
     crate::{
-        idl::Idl,
-        legacy_idl::{LegacyIdl, LegacyIdlType},
+        accounts::AccountData,
+        commands::IdlStandard,
+        error::NitrogenError,
+        idl::{Idl, IdlEnumField},
+        instructions::InstructionData,
+        legacy_idl::{
+            IdlDefinedType,
+            LegacyIdl,
+            LegacyIdlEnumField,
+            LegacyIdlEnumFields,
+            LegacyIdlType,
+        },
+        types::{EnumVariantFields, TypeData, TypeKind},
     },
     anyhow::Result,
-    std::fs::File,
+    heck::ToSnakeCase,
+    serde::de::DeserializeOwned,
+    std::fs,
 };
 
 pub fn legacy_read_idl(idl_path: &str) -> Result<LegacyIdl> {
-    let file = File::open(idl_path).expect("Failed to open file");
+    Ok(read_idl_json(idl_path)?)
+}
 
-    match serde_json::from_reader(file) {
-        Ok(idl) => Ok(idl),
-        Err(e) => {
-            println!("Error parsing legacy IDL: {:?}", e);
-            anyhow::bail!("Error parsing legacy idl: {:?}", e);
+/// Reads the JSON file at `idl_path`, locating parse errors by JSON path.
+pub fn read_idl_json<T: DeserializeOwned>(idl_path: &str) -> Result<T, NitrogenError> {
+    let json = fs::read_to_string(idl_path).map_err(NitrogenError::io("read", idl_path))?;
+    serde_json::from_str(&json).map_err(|e| {
+        let message = e.to_string();
+        let message = match message.rsplit_once(" at line ") {
+            Some((message, _)) => message.to_string(),
+            None => message,
+        };
+        NitrogenError::IdlParse {
+            path: idl_path.to_string(),
+            json_path: json_path_at(&json, e.line(), e.column()),
+            line: e.line(),
+            column: e.column(),
+            message,
         }
-    }
+    })
 }
 
-pub fn read_idl(idl_path: &str) -> Result<Idl> {
-    let file = File::open(idl_path).expect("Failed to open file");
-    match serde_json::from_reader(file) {
-        Ok(idl) => Ok(idl),
-        Err(e) => {
-            println!("Error parsing IDL: {:?}", e);
-            anyhow::bail!("Error parsing idl: {:?}", e);
+/// Returns the JSON path, e.g. `$.instructions[2].args`, of the value ending
+/// at `line` and `column` (both 1-based) of `json`, as reported by serde_json.
+fn json_path_at(json: &str, line: usize, column: usize) -> String {
+    enum Container {
+        Object(Option<String>),
+        Array(usize),
+    }
+
+    let offset = json
+        .split_inclusive('\n')
+        .take(line.saturating_sub(1))
+        .map(str::len)
+        .sum::<usize>()
+        + column.saturating_sub(1);
+    let mut containers = Vec::new();
+    let mut expects_key = false;
+    let mut chars = json.char_indices().take_while(|(index, _)| *index < offset);
+    while let Some((_, c)) = chars.next() {
+        match c {
+            '{' => {
+                containers.push(Container::Object(None));
+                expects_key = true;
+            }
+            '[' => containers.push(Container::Array(0)),
+            '}' | ']' => {
+                containers.pop();
+            }
+            ',' => match containers.last_mut() {
+                Some(Container::Array(index)) => *index += 1,
+                Some(Container::Object(_)) => expects_key = true,
+                None => {}
+            },
+            ':' => expects_key = false,
+            '"' => {
+                let mut string = String::new();
+                let mut escaped = false;
+                for (_, c) in chars.by_ref() {
+                    match c {
+                        '"' if !escaped => break,
+                        '\\' if !escaped => escaped = true,
+                        c => {
+                            string.push(c);
+                            escaped = false;
+                        }
+                    }
+                }
+                if expects_key && let Some(Container::Object(key)) = containers.last_mut() {
+                    *key = Some(string);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut path = "$".to_string();
+    for container in containers {
+        match container {
+            Container::Object(Some(key)) => path.push_str(&format!(".{key}")),
+            Container::Object(None) => {}
+            Container::Array(index) => path.push_str(&format!("[{index}]")),
         }
     }
+    path
+}
+
+/// Detects the standard of an IDL file from its contents.
+pub fn detect_idl_standard(idl_path: &str) -> Result<IdlStandard, NitrogenError> {
+    let idl: serde_json::Value = read_idl_json(idl_path)?;
+
+    if idl.get("kind").and_then(|kind| kind.as_str()) == Some("rootNode") {
+        Ok(IdlStandard::Codama)
+    } else if idl
+        .pointer("/metadata/origin")
+        .and_then(|origin| origin.as_str())
+        == Some("shank")
+    {
+        Ok(IdlStandard::Shank)
+    } else if idl.pointer("/metadata/spec").is_some() {
+        Ok(IdlStandard::Anchor)
+    } else if idl.get("version").is_some() && idl.get("name").is_some() {
+        Ok(IdlStandard::AnchorLegacy)
+    } else {
+        Err(NitrogenError::Unsupported {
+            item: idl_path.to_string(),
+            construct: "IDL standard (pass --standard anchor, anchor-legacy, codama or shank)"
+                .to_string(),
+        })
+    }
+}
+
+pub fn read_idl(idl_path: &str) -> Result<Idl> {
+    Ok(read_idl_json(idl_path)?)
 }
 
 pub fn is_primish(t: &LegacyIdlType) -> bool {
@@ -57,6 +164,154 @@ pub fn is_primish(t: &LegacyIdlType) -> bool {
     }
 }
 
+/// Splits a comma-separated filter option into names.
+pub fn parse_filter(filter: Option<String>) -> Vec<String> {
+    filter
+        .map(|f| f.split(',').map(|s| s.trim().to_string()).collect())
+        .unwrap_or_default()
+}
+
+/// Whether `name`, as written in the IDL or in snake case, is selected by
+/// `filter`. An empty filter selects everything.
+pub fn matches_filter(filter: &[String], name: &str) -> bool {
+    filter.is_empty()
+        || filter
+            .iter()
+            .any(|f| *f == name || *f == name.to_snake_case())
+}
+
+/// Calls `visit` with the name of every defined type referenced by
+/// `idl_type`.
+pub fn visit_defined_types(idl_type: &LegacyIdlType, visit: &mut impl FnMut(&str)) {
+    match idl_type {
+        LegacyIdlType::Defined { defined } => visit(defined),
+        LegacyIdlType::DefinedWithName { defined } => visit(&defined.name),
+        LegacyIdlType::Array { array: (inner, _) }
+        | LegacyIdlType::Vec { vec: inner }
+        | LegacyIdlType::Option { option: inner } => visit_defined_types(inner, visit),
+        LegacyIdlType::Tuple { tuple } => {
+            for inner in tuple {
+                visit_defined_types(inner, visit);
+            }
+        }
+        LegacyIdlType::HashMap {
+            hash_map: (key, value),
+        } => {
+            visit_defined_types(key, visit);
+            visit_defined_types(value, visit);
+        }
+        LegacyIdlType::Primitive(_) | LegacyIdlType::OptionPrimitive { .. } => {}
+    }
+}
+
+/// Field types of a defined type, one list per variant for enums.
+pub enum DefinedFields<'a> {
+    Struct(Vec<&'a LegacyIdlType>),
+    Enum(Vec<Vec<&'a LegacyIdlType>>),
+}
+
+/// Borsh size of `idl_type` when it's the same for every value, as for
+/// bytemuck-style layouts.
+pub fn legacy_fixed_size(idl: &LegacyIdl, idl_type: &LegacyIdlType) -> Option<usize> {
+    fixed_size(idl_type, &|name| {
+        let type_def = idl.types.iter().find(|type_def| type_def.name == name)?;
+        Some(match &type_def.type_.variants {
+            Some(variants) => DefinedFields::Enum(
+                variants
+                    .iter()
+                    .map(|variant| variant_field_types(variant.fields.as_ref()))
+                    .collect(),
+            ),
+            None => DefinedFields::Struct(
+                type_def
+                    .type_
+                    .fields
+                    .iter()
+                    .flatten()
+                    .map(|field| match field {
+                        LegacyIdlEnumField::Named(field) => &field.type_,
+                        LegacyIdlEnumField::Tuple(field) => field,
+                    })
+                    .collect(),
+            ),
+        })
+    })
+}
+
+/// Same as [`legacy_fixed_size`] for a 0.30 IDL.
+pub fn anchor_fixed_size(idl: &Idl, idl_type: &LegacyIdlType) -> Option<usize> {
+    fixed_size(idl_type, &|name| {
+        let type_def = idl.types.iter().find(|type_def| type_def.name == name)?;
+        Some(match &type_def.type_.variants {
+            Some(variants) => DefinedFields::Enum(
+                variants
+                    .iter()
+                    .map(|variant| variant_field_types(variant.fields.as_ref()))
+                    .collect(),
+            ),
+            None => DefinedFields::Struct(
+                type_def
+                    .type_
+                    .fields
+                    .iter()
+                    .flatten()
+                    .map(|field| match field {
+                        IdlEnumField::Named(field) => &field.type_,
+                        IdlEnumField::Tuple(field) => field,
+                    })
+                    .collect(),
+            ),
+        })
+    })
+}
+
+fn variant_field_types(fields: Option<&LegacyIdlEnumFields>) -> Vec<&LegacyIdlType> {
+    match fields {
+        Some(LegacyIdlEnumFields::Named(fields)) => {
+            fields.iter().map(|field| &field.type_).collect()
+        }
+        Some(LegacyIdlEnumFields::Tuple(fields)) => fields.iter().collect(),
+        None => Vec::new(),
+    }
+}
+
+/// Borsh size of `idl_type`, looking up defined types with `defined`.
+fn fixed_size<'a>(
+    idl_type: &LegacyIdlType,
+    defined: &impl Fn(&str) -> Option<DefinedFields<'a>>,
+) -> Option<usize> {
+    let fields_size = |fields: &[&LegacyIdlType]| {
+        fields
+            .iter()
+            .try_fold(0, |size, field| Some(size + fixed_size(field, defined)?))
+    };
+    match idl_type {
+        LegacyIdlType::Primitive(primitive) => match primitive.as_str() {
+            "bool" | "u8" | "i8" => Some(1),
+            "u16" | "i16" => Some(2),
+            "u32" | "i32" | "f32" => Some(4),
+            "u64" | "i64" | "f64" => Some(8),
+            "u128" | "i128" => Some(16),
+            "publicKey" | "pubkey" => Some(32),
+            _ => None,
+        },
+        LegacyIdlType::Array { array: (item, len) } => Some(fixed_size(item, defined)? * len),
+        LegacyIdlType::Tuple { tuple } => fields_size(&tuple.iter().collect::<Vec<_>>()),
+        LegacyIdlType::Defined { defined: name }
+        | LegacyIdlType::DefinedWithName {
+            defined: IdlDefinedType { name },
+        } => match defined(name)? {
+            DefinedFields::Struct(fields) => fields_size(&fields),
+            DefinedFields::Enum(variants) => {
+                let mut sizes = variants.iter().map(|fields| fields_size(fields));
+                let size = sizes.next()??;
+                sizes.all(|other| other == Some(size)).then_some(1 + size)
+            }
+        },
+        _ => None,
+    }
+}
+
 pub fn idl_type_to_rust_type(idl_type: &LegacyIdlType) -> (String, bool) {
     match idl_type {
         LegacyIdlType::Primitive(s) => match s.as_str() {
@@ -157,3 +412,378 @@ pub fn is_big_array(rust_type: &str) -> bool {
     }
     false
 }
+
+/// Returns a `serde(with = ...)` field attribute gated on the generated
+/// crate's `serde` feature.
+pub fn serde_with_attribute(with: &str) -> String {
+    format!("#[cfg_attr(feature = \"serde\", serde(with = \"{with}\"))]")
+}
+
+/// Returns the serde attribute for arrays longer than 32 elements, which
+/// serde can't derive. Borsh (de)serializes arrays of any length natively.
+pub fn big_array_attribute(rust_type: &str) -> Option<String> {
+    is_big_array(rust_type).then(|| serde_with_attribute("serde_big_array::BigArray"))
+}
+
+/// Whether any generated field relies on `serde-big-array`.
+pub fn needs_big_array(
+    types_data: &[TypeData],
+    accounts_data: &[AccountData],
+    instructions_data: &[InstructionData],
+) -> bool {
+    any_field(
+        types_data,
+        accounts_data,
+        instructions_data,
+        |_, attributes| {
+            attributes
+                .as_deref()
+                .is_some_and(|a| a.contains("serde_big_array"))
+        },
+    )
+}
+
+/// Whether any generated field uses a `nitrogen_instruction_builder::codec`
+/// wrapper.
+pub fn uses_codec(
+    types_data: &[TypeData],
+    accounts_data: &[AccountData],
+    instructions_data: &[InstructionData],
+) -> bool {
+    any_field(
+        types_data,
+        accounts_data,
+        instructions_data,
+        |rust_type, _| rust_type.contains("nitrogen_instruction_builder::codec::"),
+    )
+}
+
+/// Whether `uses` holds for the type and attributes of any generated field.
+fn any_field(
+    types_data: &[TypeData],
+    accounts_data: &[AccountData],
+    instructions_data: &[InstructionData],
+    uses: impl Fn(&str, &Option<String>) -> bool,
+) -> bool {
+    for type_data in types_data {
+        if type_data
+            .fields
+            .iter()
+            .any(|f| uses(&f.rust_type, &f.attributes))
+        {
+            return true;
+        }
+        if let TypeKind::Enum(variants) = &type_data.kind {
+            for variant in variants {
+                match &variant.fields {
+                    Some(EnumVariantFields::Named(fields) | EnumVariantFields::Unnamed(fields))
+                        if fields.iter().any(|f| uses(&f.rust_type, &f.attributes)) =>
+                    {
+                        return true;
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+    accounts_data.iter().any(|account| {
+        account
+            .fields
+            .iter()
+            .any(|f| uses(&f.rust_type, &f.attributes))
+    }) || instructions_data.iter().any(|instruction| {
+        instruction
+            .args
+            .iter()
+            .any(|a| uses(&a.rust_type, &a.attributes))
+    })
+}
+
+/// Returns the entries appended to the generated crate's `serde` feature.
+pub fn serde_features(needs_big_array: bool, builder_serde: bool) -> String {
+    let mut features = String::new();
+    if needs_big_array {
+        features.push_str(", \"dep:serde-big-array\"");
+    }
+    if builder_serde {
+        features.push_str(", \"nitrogen-instruction-builder/serde\"");
+    }
+    features
+}
+
+/// Returns the `serde(with = ...)` attribute giving `rust_type` a
+/// JSON-friendly representation, if one applies.
+///
+/// Only a pubkey or large integer, directly or in one `Option`, `Vec` or
+/// array, and byte vectors and arrays are adapted. Other composites, such as
+/// `Option<[u8; N]>` or `Vec<Option<Pubkey>>`, keep serde's representation.
+pub fn human_readable_serde_attribute(rust_type: &str) -> Option<String> {
+    const DISPLAY_TYPES: [&str; 5] = ["solana_pubkey::Pubkey", "u64", "i64", "u128", "i128"];
+    let inner = |wrapper: &str| {
+        rust_type
+            .strip_prefix(wrapper)
+            .and_then(|t| t.strip_suffix('>'))
+            .is_some_and(|t| DISPLAY_TYPES.contains(&t))
+    };
+    let array_item = rust_type
+        .strip_prefix('[')
+        .and_then(|t| t.split_once(';'))
+        .map(|(item, _)| item);
+    let adapter = if DISPLAY_TYPES.contains(&rust_type) {
+        "display_from_str"
+    } else if inner("Option<") {
+        "option_display_from_str"
+    } else if inner("Vec<") {
+        "vec_display_from_str"
+    } else if array_item.is_some_and(|item| DISPLAY_TYPES.contains(&item)) {
+        "array_display_from_str"
+    } else if rust_type == "Vec<u8>" {
+        "hex_vec"
+    } else if rust_type.starts_with("[u8;") {
+        "hex_array"
+    } else {
+        return None;
+    };
+    Some(serde_with_attribute(&format!(
+        "nitrogen_instruction_builder::human_readable::{adapter}"
+    )))
+}
+
+/// Replaces serde attributes of generated fields with human-readable
+/// adapters: base58 pubkeys, hex byte arrays and string-encoded 64/128-bit
+/// integers.
+pub fn apply_human_readable_serde(
+    types_data: &mut [TypeData],
+    accounts_data: &mut [AccountData],
+    instructions_data: &mut [InstructionData],
+) {
+    for type_data in types_data.iter_mut() {
+        for field in &mut type_data.fields {
+            if let Some(attributes) = human_readable_serde_attribute(&field.rust_type) {
+                field.attributes = Some(attributes);
+            }
+        }
+        if let TypeKind::Enum(variants) = &mut type_data.kind {
+            for variant in variants {
+                if let Some(EnumVariantFields::Named(fields) | EnumVariantFields::Unnamed(fields)) =
+                    &mut variant.fields
+                {
+                    for field in fields {
+                        if let Some(attributes) = human_readable_serde_attribute(&field.rust_type) {
+                            field.attributes = Some(attributes);
+                        }
+                    }
+                }
+            }
+        }
+    }
+    for account in accounts_data.iter_mut() {
+        for field in &mut account.fields {
+            if let Some(attributes) = human_readable_serde_attribute(&field.rust_type) {
+                field.attributes = Some(attributes);
+            }
+        }
+    }
+    for instruction in instructions_data.iter_mut() {
+        for arg in &mut instruction.args {
+            if let Some(attributes) = human_readable_serde_attribute(&arg.rust_type) {
+                arg.attributes = Some(attributes);
+            }
+        }
+    }
+}
+
+/// Pretty-prints generated Rust code, failing with the offending line of
+/// `filename` when it doesn't parse.
+pub fn format_generated_code(filename: &str, code: &str) -> Result<String> {
+    match syn::parse_file(code) {
+        Ok(file) => Ok(prettyplease::unparse(&file)),
+        Err(e) => {
+            let start = e.span().start();
+            let line = code
+                .lines()
+                .nth(start.line.saturating_sub(1))
+                .unwrap_or_default();
+            anyhow::bail!(
+                "Generated code doesn't parse at {filename}:{}:{}: {e}\n    {}",
+                start.line,
+                start.column + 1,
+                line.trim()
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::types::{EnumVariantData, FieldData},
+    };
+
+    #[test]
+    fn test_read_idl_json() {
+        let idl_path =
+            std::env::temp_dir().join(format!("nitrogen-idl-{}.json", std::process::id()));
+        let idl_path = idl_path.to_str().unwrap();
+        fs::write(
+            idl_path,
+            r#"{
+  "version": "0.1.0",
+  "name": "escrow",
+  "instructions": [
+    { "name": "initialize", "accounts": [], "args": [] },
+    {
+      "name": "cancel",
+      "accounts": [{ "name": "maker", "isMut": "yes", "isSigner": true }],
+      "args": []
+    }
+  ]
+}"#,
+        )
+        .unwrap();
+        let Err(NitrogenError::IdlParse {
+            json_path,
+            line,
+            message,
+            ..
+        }) = read_idl_json::<LegacyIdl>(idl_path)
+        else {
+            panic!("expected a parse error");
+        };
+        assert_eq!(json_path, "$.instructions[1].accounts[0].isMut");
+        assert_eq!(line, 8);
+        assert_eq!(message, "invalid type: string \"yes\", expected a boolean");
+
+        fs::remove_file(idl_path).unwrap();
+        assert!(matches!(
+            read_idl_json::<LegacyIdl>(idl_path),
+            Err(NitrogenError::Io { .. })
+        ));
+    }
+
+    #[test]
+    fn test_format_generated_code() {
+        assert_eq!(
+            format_generated_code("ok.rs", "pub struct  A{pub a:u8,}").unwrap(),
+            "pub struct A {\n    pub a: u8,\n}\n"
+        );
+        let error = format_generated_code("bad.rs", "pub struct A {\n    pub a: ,\n}")
+            .unwrap_err()
+            .to_string();
+        assert!(error.starts_with("Generated code doesn't parse at bad.rs:2:12"));
+        assert!(error.ends_with("pub a: ,"));
+    }
+
+    #[test]
+    fn test_detect_idl_standard() {
+        let detect = |file: &str| {
+            detect_idl_standard(&format!("{}/../../idls/{file}", env!("CARGO_MANIFEST_DIR")))
+                .unwrap()
+        };
+        assert_eq!(detect("codama_counter.json"), IdlStandard::Codama);
+        assert_eq!(
+            detect("token_messenger_minter_v2.json"),
+            IdlStandard::Anchor
+        );
+        assert_eq!(detect("legacy_escrow.json"), IdlStandard::AnchorLegacy);
+        assert_eq!(detect("shank_vault.json"), IdlStandard::Shank);
+
+        let idl_path =
+            std::env::temp_dir().join(format!("nitrogen-unknown-{}.json", std::process::id()));
+        let idl_path = idl_path.to_str().unwrap();
+        fs::write(idl_path, r#"{ "instructions": [] }"#).unwrap();
+        let result = detect_idl_standard(idl_path);
+        fs::remove_file(idl_path).unwrap();
+        assert!(matches!(result, Err(NitrogenError::Unsupported { .. })));
+    }
+
+    #[test]
+    fn test_human_readable_serde_attribute() {
+        let adapter = |t: &str| {
+            human_readable_serde_attribute(t).map(|a| {
+                a.trim_start_matches(
+                    "#[cfg_attr(feature = \"serde\", serde(with = \
+                     \"nitrogen_instruction_builder::human_readable::",
+                )
+                .trim_end_matches("\"))]")
+                .to_string()
+            })
+        };
+        assert_eq!(
+            adapter("solana_pubkey::Pubkey").as_deref(),
+            Some("display_from_str")
+        );
+        assert_eq!(adapter("u128").as_deref(), Some("display_from_str"));
+        assert_eq!(
+            adapter("Option<u64>").as_deref(),
+            Some("option_display_from_str")
+        );
+        assert_eq!(
+            adapter("Vec<solana_pubkey::Pubkey>").as_deref(),
+            Some("vec_display_from_str")
+        );
+        assert_eq!(adapter("Vec<u8>").as_deref(), Some("hex_vec"));
+        assert_eq!(adapter("[u8; 64]").as_deref(), Some("hex_array"));
+        assert_eq!(adapter("u32"), None);
+        assert_eq!(
+            adapter("[solana_pubkey::Pubkey; 11]").as_deref(),
+            Some("array_display_from_str")
+        );
+        assert_eq!(
+            adapter("[u64; 4]").as_deref(),
+            Some("array_display_from_str")
+        );
+        assert_eq!(adapter("Option<Vec<u64>>"), None);
+        assert_eq!(adapter("Option<[u8; 32]>"), None);
+        assert_eq!(adapter("Vec<Option<solana_pubkey::Pubkey>>"), None);
+        assert_eq!(adapter("[u32; 4]"), None);
+    }
+
+    #[test]
+    fn test_human_readable_tuple_variant() {
+        let field = |rust_type: &str| FieldData {
+            name: String::new(),
+            rust_type: rust_type.to_string(),
+            is_pubkey: false,
+            attributes: None,
+        };
+        let mut types = vec![TypeData {
+            name: "Transfer".to_string(),
+            fields: Vec::new(),
+            kind: TypeKind::Enum(vec![EnumVariantData {
+                name: "To".to_string(),
+                fields: Some(EnumVariantFields::Unnamed(vec![
+                    field("solana_pubkey::Pubkey"),
+                    field("u8"),
+                ])),
+            }]),
+            requires_imports: false,
+        }];
+        apply_human_readable_serde(&mut types, &mut [], &mut []);
+        let TypeKind::Enum(variants) = &types[0].kind else {
+            panic!("Transfer is an enum");
+        };
+        let Some(EnumVariantFields::Unnamed(fields)) = &variants[0].fields else {
+            panic!("Transfer::To is a tuple variant");
+        };
+        assert!(
+            fields[0]
+                .attributes
+                .as_deref()
+                .unwrap()
+                .contains("display_from_str")
+        );
+        assert_eq!(fields[1].attributes, None);
+    }
+
+    #[test]
+    fn test_big_array_attribute() {
+        assert_eq!(
+            big_array_attribute("[u8; 64]").as_deref(),
+            Some("#[cfg_attr(feature = \"serde\", serde(with = \"serde_big_array::BigArray\"))]")
+        );
+        assert_eq!(big_array_attribute("[u8; 32]"), None);
+        assert_eq!(big_array_attribute("Vec<u8>"), None);
+    }
+}
@@ -0,0 +1,74 @@
+use {borsh::BorshDeserialize, std::io};
+
+/// Program account identified by a discriminator and/or its size, followed by
+/// Borsh data.
+///
+/// Implemented by every generated account struct.
+pub trait AccountDeserialize: BorshDeserialize {
+    /// Bytes identifying this account type at [`Self::DISCRIMINATOR_OFFSET`].
+    const DISCRIMINATOR: &'static [u8];
+
+    /// Offset of [`Self::DISCRIMINATOR`] in the account data. A discriminator
+    /// at offset 0 is a prefix skipped before decoding, otherwise it is part
+    /// of the decoded fields.
+    const DISCRIMINATOR_OFFSET: usize = 0;
+
+    /// Exact data length of this account type, for accounts identified by
+    /// size.
+    const SIZE: Option<usize> = None;
+
+    /// Returns whether `data` holds an account of this type.
+    fn matches_account_data(data: &[u8]) -> bool {
+        let offset = Self::DISCRIMINATOR_OFFSET;
+        Self::SIZE.is_none_or(|size| data.len() == size)
+            && data.get(offset..offset + Self::DISCRIMINATOR.len()) == Some(Self::DISCRIMINATOR)
+    }
+
+    /// Decodes raw account data, verifying and skipping the discriminator.
+    ///
+    /// Trailing bytes (e.g. reserved space) are ignored.
+    fn try_from_account_data(data: &[u8]) -> io::Result<Self> {
+        if !Self::matches_account_data(data) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "account discriminator mismatch",
+            ));
+        }
+        let mut body = match Self::DISCRIMINATOR_OFFSET {
+            0 => &data[Self::DISCRIMINATOR.len()..],
+            _ => data,
+        };
+        Self::deserialize(&mut body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, borsh::BorshSerialize};
+
+    #[derive(Debug, PartialEq, BorshSerialize, BorshDeserialize)]
+    struct Mint {
+        supply: u64,
+        kind: u8,
+    }
+
+    impl AccountDeserialize for Mint {
+        const DISCRIMINATOR: &'static [u8] = &[2];
+        const DISCRIMINATOR_OFFSET: usize = 8;
+        const SIZE: Option<usize> = Some(9);
+    }
+
+    #[test]
+    fn test_offset_and_size_discriminator() {
+        let mut data = Vec::new();
+        Mint { supply: 7, kind: 2 }.serialize(&mut data).unwrap();
+        assert_eq!(Mint::try_from_account_data(&data).unwrap(), Mint {
+            supply: 7,
+            kind: 2
+        });
+
+        data.push(0);
+        assert!(!Mint::matches_account_data(&data));
+        assert!(!Mint::matches_account_data(&[0; 9]));
+    }
+}
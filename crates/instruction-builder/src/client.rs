@@ -0,0 +1,299 @@
+//! Async account fetching used by generated program clients.
+//!
+//! Generated clients are generic over [`AccountFetcher`], which is implemented
+//! for the nonblocking [`RpcClient`] and is small enough to mock in tests.
+
+pub use solana_rpc_client_api::{
+    client_error::{Error as ClientError, ErrorKind as ClientErrorKind, Result as ClientResult},
+    filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType},
+};
+use {
+    crate::AccountDeserialize,
+    solana_account_decoder_client_types::UiAccountEncoding,
+    solana_pubkey::Pubkey,
+    solana_rpc_client::nonblocking::rpc_client::RpcClient,
+    solana_rpc_client_api::config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    std::sync::Arc,
+};
+
+/// Minimal RPC surface needed to fetch program accounts.
+#[async_trait::async_trait]
+pub trait AccountFetcher: Send + Sync {
+    /// Returns the data of the account at `address`.
+    async fn get_account_data(&self, address: &Pubkey) -> ClientResult<Vec<u8>>;
+
+    /// Returns the data of each account, `None` for missing accounts.
+    async fn get_multiple_account_data(
+        &self,
+        addresses: &[Pubkey],
+    ) -> ClientResult<Vec<Option<Vec<u8>>>>;
+
+    /// Returns the address and data of every account owned by `program_id`
+    /// matching all `filters`.
+    async fn get_program_account_data(
+        &self,
+        program_id: &Pubkey,
+        filters: Vec<RpcFilterType>,
+    ) -> ClientResult<Vec<(Pubkey, Vec<u8>)>>;
+}
+
+#[async_trait::async_trait]
+impl AccountFetcher for RpcClient {
+    async fn get_account_data(&self, address: &Pubkey) -> ClientResult<Vec<u8>> {
+        RpcClient::get_account_data(self, address).await
+    }
+
+    async fn get_multiple_account_data(
+        &self,
+        addresses: &[Pubkey],
+    ) -> ClientResult<Vec<Option<Vec<u8>>>> {
+        Ok(self
+            .get_multiple_accounts(addresses)
+            .await?
+            .into_iter()
+            .map(|account| account.map(|a| a.data))
+            .collect())
+    }
+
+    async fn get_program_account_data(
+        &self,
+        program_id: &Pubkey,
+        filters: Vec<RpcFilterType>,
+    ) -> ClientResult<Vec<(Pubkey, Vec<u8>)>> {
+        let accounts = self
+            .get_program_ui_accounts_with_config(program_id, RpcProgramAccountsConfig {
+                filters: Some(filters),
+                account_config: RpcAccountInfoConfig {
+                    encoding: Some(UiAccountEncoding::Base64),
+                    ..Default::default()
+                },
+                ..Default::default()
+            })
+            .await?;
+        accounts
+            .into_iter()
+            .map(|(address, account)| {
+                let data = account.data.decode().ok_or_else(|| {
+                    ClientErrorKind::Custom(format!(
+                        "Failed to decode the data of account {address}"
+                    ))
+                })?;
+                Ok((address, data))
+            })
+            .collect()
+    }
+}
+
+#[async_trait::async_trait]
+impl<T: AccountFetcher + ?Sized> AccountFetcher for &T {
+    async fn get_account_data(&self, address: &Pubkey) -> ClientResult<Vec<u8>> {
+        (**self).get_account_data(address).await
+    }
+
+    async fn get_multiple_account_data(
+        &self,
+        addresses: &[Pubkey],
+    ) -> ClientResult<Vec<Option<Vec<u8>>>> {
+        (**self).get_multiple_account_data(addresses).await
+    }
+
+    async fn get_program_account_data(
+        &self,
+        program_id: &Pubkey,
+        filters: Vec<RpcFilterType>,
+    ) -> ClientResult<Vec<(Pubkey, Vec<u8>)>> {
+        (**self).get_program_account_data(program_id, filters).await
+    }
+}
+
+#[async_trait::async_trait]
+impl<T: AccountFetcher + ?Sized> AccountFetcher for Arc<T> {
+    async fn get_account_data(&self, address: &Pubkey) -> ClientResult<Vec<u8>> {
+        self.as_ref().get_account_data(address).await
+    }
+
+    async fn get_multiple_account_data(
+        &self,
+        addresses: &[Pubkey],
+    ) -> ClientResult<Vec<Option<Vec<u8>>>> {
+        self.as_ref().get_multiple_account_data(addresses).await
+    }
+
+    async fn get_program_account_data(
+        &self,
+        program_id: &Pubkey,
+        filters: Vec<RpcFilterType>,
+    ) -> ClientResult<Vec<(Pubkey, Vec<u8>)>> {
+        self.as_ref()
+            .get_program_account_data(program_id, filters)
+            .await
+    }
+}
+
+/// Fetches and decodes the account at `address`.
+pub async fn fetch_account<T, R>(rpc: &R, address: &Pubkey) -> ClientResult<T>
+where
+    T: AccountDeserialize,
+    R: AccountFetcher + ?Sized,
+{
+    let data = rpc.get_account_data(address).await?;
+    Ok(T::try_from_account_data(&data)?)
+}
+
+/// Fetches and decodes several accounts in one request, `None` for missing
+/// accounts.
+pub async fn fetch_multiple_accounts<T, R>(
+    rpc: &R,
+    addresses: &[Pubkey],
+) -> ClientResult<Vec<Option<T>>>
+where
+    T: AccountDeserialize,
+    R: AccountFetcher + ?Sized,
+{
+    rpc.get_multiple_account_data(addresses)
+        .await?
+        .into_iter()
+        .map(|data| {
+            data.map(|d| T::try_from_account_data(&d))
+                .transpose()
+                .map_err(Into::into)
+        })
+        .collect()
+}
+
+/// Fetches every `T` account owned by `program_id` matching `filters`.
+///
+/// Filters on [`AccountDeserialize::DISCRIMINATOR`] and
+/// [`AccountDeserialize::SIZE`] are prepended so only accounts of type `T` are
+/// returned.
+pub async fn fetch_program_accounts<T, R>(
+    rpc: &R,
+    program_id: &Pubkey,
+    filters: Vec<RpcFilterType>,
+) -> ClientResult<Vec<(Pubkey, T)>>
+where
+    T: AccountDeserialize,
+    R: AccountFetcher + ?Sized,
+{
+    let mut all_filters = Vec::with_capacity(filters.len() + 2);
+    if !T::DISCRIMINATOR.is_empty() {
+        all_filters.push(RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+            T::DISCRIMINATOR_OFFSET,
+            T::DISCRIMINATOR,
+        )));
+    }
+    if let Some(size) = T::SIZE {
+        all_filters.push(RpcFilterType::DataSize(size as u64));
+    }
+    all_filters.extend(filters);
+    rpc.get_program_account_data(program_id, all_filters)
+        .await?
+        .into_iter()
+        .map(|(address, data)| Ok((address, T::try_from_account_data(&data)?)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        borsh::{BorshDeserialize, BorshSerialize},
+        std::collections::HashMap,
+    };
+
+    #[derive(Debug, PartialEq, BorshSerialize, BorshDeserialize)]
+    struct Counter {
+        count: u64,
+    }
+
+    impl AccountDeserialize for Counter {
+        const DISCRIMINATOR: &'static [u8] = &[1, 2, 3, 4, 5, 6, 7, 8];
+    }
+
+    fn account_data(count: u64) -> Vec<u8> {
+        let mut data = Counter::DISCRIMINATOR.to_vec();
+        Counter { count }.serialize(&mut data).unwrap();
+        data
+    }
+
+    #[derive(Default)]
+    struct MockFetcher {
+        accounts: HashMap<Pubkey, Vec<u8>>,
+    }
+
+    #[async_trait::async_trait]
+    impl AccountFetcher for MockFetcher {
+        async fn get_account_data(&self, address: &Pubkey) -> ClientResult<Vec<u8>> {
+            self.accounts.get(address).cloned().ok_or_else(|| {
+                ClientError::from(std::io::Error::new(
+                    std::io::ErrorKind::NotFound,
+                    address.to_string(),
+                ))
+            })
+        }
+
+        async fn get_multiple_account_data(
+            &self,
+            addresses: &[Pubkey],
+        ) -> ClientResult<Vec<Option<Vec<u8>>>> {
+            Ok(addresses
+                .iter()
+                .map(|a| self.accounts.get(a).cloned())
+                .collect())
+        }
+
+        async fn get_program_account_data(
+            &self,
+            _program_id: &Pubkey,
+            filters: Vec<RpcFilterType>,
+        ) -> ClientResult<Vec<(Pubkey, Vec<u8>)>> {
+            Ok(self
+                .accounts
+                .iter()
+                .filter(|(_, data)| {
+                    filters.iter().all(|f| match f {
+                        RpcFilterType::Memcmp(m) => m.bytes_match(data),
+                        RpcFilterType::DataSize(size) => data.len() as u64 == *size,
+                        _ => true,
+                    })
+                })
+                .map(|(k, v)| (*k, v.clone()))
+                .collect())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fetch_account() {
+        let address = Pubkey::new_unique();
+        let mut rpc = MockFetcher::default();
+        rpc.accounts.insert(address, account_data(42));
+
+        let counter: Counter = fetch_account(&rpc, &address).await.unwrap();
+        assert_eq!(counter, Counter { count: 42 });
+
+        rpc.accounts.insert(address, vec![0; 16]);
+        assert!(fetch_account::<Counter, _>(&rpc, &address).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_multiple_and_program_accounts() {
+        let present = Pubkey::new_unique();
+        let other = Pubkey::new_unique();
+        let missing = Pubkey::new_unique();
+        let mut rpc = MockFetcher::default();
+        rpc.accounts.insert(present, account_data(7));
+        rpc.accounts.insert(other, vec![9; 16]);
+
+        let accounts: Vec<Option<Counter>> = fetch_multiple_accounts(&rpc, &[present, missing])
+            .await
+            .unwrap();
+        assert_eq!(accounts, vec![Some(Counter { count: 7 }), None]);
+
+        let rpc = Arc::new(rpc);
+        let all: Vec<(Pubkey, Counter)> =
+            fetch_program_accounts(&rpc, &Pubkey::new_unique(), vec![])
+                .await
+                .unwrap();
+        assert_eq!(all, vec![(present, Counter { count: 7 })]);
+    }
+}
@@ -0,0 +1,543 @@
+//! Wrapper types for Codama layouts that differ from default Borsh.
+//!
+//! Emitted by the generator in place of plain Rust types when an IDL sets a
+//! non-default endianness, length prefix, fixed size or padding. Each wrapper
+//! derefs to the value it wraps and is serialized by serde as that value.
+
+use {
+    borsh::{BorshDeserialize, BorshSerialize},
+    std::{
+        io::{self, Read, Write},
+        marker::PhantomData,
+        ops::{Deref, DerefMut},
+    },
+};
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// Integer types usable as length and option prefixes.
+pub trait LengthPrefix: BorshSerialize + BorshDeserialize {
+    fn from_len(len: usize) -> io::Result<Self>;
+    fn into_len(self) -> io::Result<usize>;
+}
+
+macro_rules! impl_length_prefix {
+    ($($t:ty),*) => {$(
+        impl LengthPrefix for $t {
+            fn from_len(len: usize) -> io::Result<Self> {
+                Self::try_from(len).map_err(|_| invalid_data("length overflows prefix"))
+            }
+
+            fn into_len(self) -> io::Result<usize> {
+                usize::try_from(self as u64).map_err(|_| invalid_data("length overflows usize"))
+            }
+        }
+    )*};
+}
+
+impl_length_prefix!(u8, u16, u32, u64);
+
+macro_rules! impl_wrapper {
+    ([$($generics:tt)*] $wrapper:ty => $inner:ty, |$value:ident| $new:expr) => {
+        impl<$($generics)*> Deref for $wrapper {
+            type Target = $inner;
+
+            fn deref(&self) -> &$inner {
+                &self.0
+            }
+        }
+
+        impl<$($generics)*> DerefMut for $wrapper {
+            fn deref_mut(&mut self) -> &mut $inner {
+                &mut self.0
+            }
+        }
+
+        impl<$($generics)*> From<$inner> for $wrapper {
+            fn from($value: $inner) -> Self {
+                $new
+            }
+        }
+    };
+}
+
+/// Number serialized big-endian.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct BigEndian<T>(pub T);
+
+impl_wrapper!([T] BigEndian<T> => T, |value| Self(value));
+
+macro_rules! impl_big_endian {
+    ($($t:ty),*) => {$(
+        impl BorshSerialize for BigEndian<$t> {
+            fn serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+                writer.write_all(&self.0.to_be_bytes())
+            }
+        }
+
+        impl BorshDeserialize for BigEndian<$t> {
+            fn deserialize_reader<R: Read>(reader: &mut R) -> io::Result<Self> {
+                let mut bytes = [0u8; size_of::<$t>()];
+                reader.read_exact(&mut bytes)?;
+                Ok(Self(<$t>::from_be_bytes(bytes)))
+            }
+        }
+    )*};
+}
+
+impl_big_endian!(u16, u32, u64, u128, i16, i32, i64, i128, f32, f64);
+
+impl<T: LengthPrefix> LengthPrefix for BigEndian<T>
+where
+    Self: BorshSerialize + BorshDeserialize,
+{
+    fn from_len(len: usize) -> io::Result<Self> {
+        T::from_len(len).map(Self)
+    }
+
+    fn into_len(self) -> io::Result<usize> {
+        self.0.into_len()
+    }
+}
+
+/// `u16` in Solana's compact "shortvec" encoding of 1 to 3 bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct ShortU16(pub u16);
+
+impl_wrapper!([] ShortU16 => u16, |value| Self(value));
+
+impl BorshSerialize for ShortU16 {
+    fn serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        let mut rest = self.0;
+        loop {
+            let byte = (rest & 0x7f) as u8;
+            rest >>= 7;
+            if rest == 0 {
+                return writer.write_all(&[byte]);
+            }
+            writer.write_all(&[byte | 0x80])?;
+        }
+    }
+}
+
+impl BorshDeserialize for ShortU16 {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let mut value = 0u32;
+        for shift in [0, 7, 14] {
+            let byte = u8::deserialize_reader(reader)?;
+            value |= u32::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return u16::try_from(value)
+                    .map(Self)
+                    .map_err(|_| invalid_data("short u16 overflow"));
+            }
+        }
+        Err(invalid_data("short u16 longer than 3 bytes"))
+    }
+}
+
+impl LengthPrefix for ShortU16 {
+    fn from_len(len: usize) -> io::Result<Self> {
+        u16::from_len(len).map(Self)
+    }
+
+    fn into_len(self) -> io::Result<usize> {
+        self.0.into_len()
+    }
+}
+
+/// `Vec<T>` prefixed by its length as `P` instead of Borsh's `u32`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct PrefixedVec<P, T>(
+    pub Vec<T>,
+    #[cfg_attr(feature = "serde", serde(skip))] pub PhantomData<P>,
+);
+
+impl_wrapper!([P, T] PrefixedVec<P, T> => Vec<T>, |value| Self(value, PhantomData));
+
+impl<P: LengthPrefix, T: BorshSerialize> BorshSerialize for PrefixedVec<P, T> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        P::from_len(self.0.len())?.serialize(writer)?;
+        for item in &self.0 {
+            item.serialize(writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl<P: LengthPrefix, T: BorshDeserialize> BorshDeserialize for PrefixedVec<P, T> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let len = P::deserialize_reader(reader)?.into_len()?;
+        let items = (0..len)
+            .map(|_| T::deserialize_reader(reader))
+            .collect::<io::Result<_>>()?;
+        Ok(Self(items, PhantomData))
+    }
+}
+
+/// `String` prefixed by its byte length as `P` instead of Borsh's `u32`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct PrefixedString<P>(
+    pub String,
+    #[cfg_attr(feature = "serde", serde(skip))] pub PhantomData<P>,
+);
+
+impl_wrapper!([P] PrefixedString<P> => String, |value| Self(value, PhantomData));
+
+impl<P: LengthPrefix> BorshSerialize for PrefixedString<P> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        P::from_len(self.0.len())?.serialize(writer)?;
+        writer.write_all(self.0.as_bytes())
+    }
+}
+
+impl<P: LengthPrefix> BorshDeserialize for PrefixedString<P> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let len = P::deserialize_reader(reader)?.into_len()?;
+        // The length comes from the data, so it isn't trusted to allocate.
+        let mut bytes = Vec::new();
+        reader.take(len as u64).read_to_end(&mut bytes)?;
+        if bytes.len() != len {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "string shorter than its length prefix",
+            ));
+        }
+        let string = String::from_utf8(bytes).map_err(|_| invalid_data("invalid utf-8"))?;
+        Ok(Self(string, PhantomData))
+    }
+}
+
+/// `Option<T>` with a `P` presence flag instead of Borsh's `u8`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct PrefixedOption<P, T>(
+    pub Option<T>,
+    #[cfg_attr(feature = "serde", serde(skip))] pub PhantomData<P>,
+);
+
+impl_wrapper!([P, T] PrefixedOption<P, T> => Option<T>, |value| Self(value, PhantomData));
+
+impl<P: LengthPrefix, T: BorshSerialize> BorshSerialize for PrefixedOption<P, T> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        P::from_len(usize::from(self.0.is_some()))?.serialize(writer)?;
+        match &self.0 {
+            Some(value) => value.serialize(writer),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<P: LengthPrefix, T: BorshDeserialize> BorshDeserialize for PrefixedOption<P, T> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let value = match P::deserialize_reader(reader)?.into_len()? {
+            0 => None,
+            1 => Some(T::deserialize_reader(reader)?),
+            _ => return Err(invalid_data("invalid option flag")),
+        };
+        Ok(Self(value, PhantomData))
+    }
+}
+
+/// UTF-8 string stored in exactly `N` bytes, zero-padded.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct FixedString<const N: usize>(pub String);
+
+impl_wrapper!([const N: usize] FixedString<N> => String, |value| Self(value));
+
+impl<const N: usize> BorshSerialize for FixedString<N> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        let bytes = self.0.as_bytes();
+        if bytes.len() > N {
+            return Err(invalid_data("string longer than its fixed size"));
+        }
+        writer.write_all(bytes)?;
+        writer.write_all(&[0u8; N][bytes.len()..])
+    }
+}
+
+impl<const N: usize> BorshDeserialize for FixedString<N> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let mut bytes = [0u8; N];
+        reader.read_exact(&mut bytes)?;
+        let len = bytes.iter().rposition(|b| *b != 0).map_or(0, |i| i + 1);
+        let string =
+            String::from_utf8(bytes[..len].to_vec()).map_err(|_| invalid_data("invalid utf-8"))?;
+        Ok(Self(string))
+    }
+}
+
+/// `T` stored in exactly `N` bytes, zero-padded.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct Padded<const N: usize, T>(pub T);
+
+impl_wrapper!([const N: usize, T] Padded<N, T> => T, |value| Self(value));
+
+impl<const N: usize, T: BorshSerialize> BorshSerialize for Padded<N, T> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        let bytes = borsh::to_vec(&self.0)?;
+        if bytes.len() > N {
+            return Err(invalid_data("value larger than its fixed size"));
+        }
+        writer.write_all(&bytes)?;
+        writer.write_all(&[0u8; N][bytes.len()..])
+    }
+}
+
+impl<const N: usize, T: BorshDeserialize> BorshDeserialize for Padded<N, T> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let mut bytes = [0u8; N];
+        reader.read_exact(&mut bytes)?;
+        Ok(Self(T::deserialize(&mut bytes.as_slice())?))
+    }
+}
+
+/// `T` preceded by `N` zero bytes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct PrePadded<const N: usize, T>(pub T);
+
+impl_wrapper!([const N: usize, T] PrePadded<N, T> => T, |value| Self(value));
+
+impl<const N: usize, T: BorshSerialize> BorshSerialize for PrePadded<N, T> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(&[0u8; N])?;
+        self.0.serialize(writer)
+    }
+}
+
+impl<const N: usize, T: BorshDeserialize> BorshDeserialize for PrePadded<N, T> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> io::Result<Self> {
+        reader.read_exact(&mut [0u8; N])?;
+        Ok(Self(T::deserialize_reader(reader)?))
+    }
+}
+
+/// `T` followed by `N` zero bytes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct PostPadded<const N: usize, T>(pub T);
+
+impl_wrapper!([const N: usize, T] PostPadded<N, T> => T, |value| Self(value));
+
+impl<const N: usize, T: BorshSerialize> BorshSerialize for PostPadded<N, T> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        self.0.serialize(writer)?;
+        writer.write_all(&[0u8; N])
+    }
+}
+
+impl<const N: usize, T: BorshDeserialize> BorshDeserialize for PostPadded<N, T> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let value = T::deserialize_reader(reader)?;
+        reader.read_exact(&mut [0u8; N])?;
+        Ok(Self(value))
+    }
+}
+
+/// Items filling the rest of the data, without a length prefix.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct RemainderVec<T>(pub Vec<T>);
+
+impl_wrapper!([T] RemainderVec<T> => Vec<T>, |value| Self(value));
+
+impl<T: BorshSerialize> BorshSerialize for RemainderVec<T> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        for item in &self.0 {
+            item.serialize(writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: BorshDeserialize> BorshDeserialize for RemainderVec<T> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+        let mut rest = bytes.as_slice();
+        let mut items = Vec::new();
+        while !rest.is_empty() {
+            items.push(T::deserialize(&mut rest)?);
+        }
+        Ok(Self(items))
+    }
+}
+
+/// UTF-8 string filling the rest of the data, without a length prefix.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct RemainderString(pub String);
+
+impl_wrapper!([] RemainderString => String, |value| Self(value));
+
+impl BorshSerialize for RemainderString {
+    fn serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(self.0.as_bytes())
+    }
+}
+
+impl BorshDeserialize for RemainderString {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let mut string = String::new();
+        reader.read_to_string(&mut string)?;
+        Ok(Self(string))
+    }
+}
+
+/// `Option<T>` that is `None` when no data remains, without a prefix.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct RemainderOption<T>(pub Option<T>);
+
+impl_wrapper!([T] RemainderOption<T> => Option<T>, |value| Self(value));
+
+impl<T: BorshSerialize> BorshSerialize for RemainderOption<T> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        match &self.0 {
+            Some(value) => value.serialize(writer),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T: BorshDeserialize> BorshDeserialize for RemainderOption<T> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+        if bytes.is_empty() {
+            return Ok(Self(None));
+        }
+        Ok(Self(Some(T::deserialize(&mut bytes.as_slice())?)))
+    }
+}
+
+/// `Option<T>` stored as `T`, with `None` written as `T::default()`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct ZeroableOption<T>(pub Option<T>);
+
+impl_wrapper!([T] ZeroableOption<T> => Option<T>, |value| Self(value));
+
+impl<T: BorshSerialize + Default> BorshSerialize for ZeroableOption<T> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        match &self.0 {
+            Some(value) => value.serialize(writer),
+            None => T::default().serialize(writer),
+        }
+    }
+}
+
+impl<T: BorshDeserialize + Default + PartialEq> BorshDeserialize for ZeroableOption<T> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let value = T::deserialize_reader(reader)?;
+        Ok(Self((value != T::default()).then_some(value)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip<T>(value: T, expected: &[u8])
+    where
+        T: BorshSerialize + BorshDeserialize + PartialEq + std::fmt::Debug,
+    {
+        let bytes = borsh::to_vec(&value).unwrap();
+        assert_eq!(bytes, expected);
+        assert_eq!(T::try_from_slice(&bytes).unwrap(), value);
+    }
+
+    #[test]
+    fn test_codec_layouts() {
+        round_trip(BigEndian(0x0102u16), &[1, 2]);
+        round_trip(ShortU16(0x80), &[0x80, 0x01]);
+        round_trip(PrefixedVec::<u8, u16>::from(vec![1, 2]), &[2, 1, 0, 2, 0]);
+        round_trip(PrefixedString::<u16>::from("hi".to_string()), &[
+            2, 0, b'h', b'i',
+        ]);
+        round_trip(PrefixedOption::<u32, u8>::from(Some(7)), &[1, 0, 0, 0, 7]);
+        round_trip(PrefixedVec::<ShortU16, u8>::from(vec![7; 128]), &{
+            let mut bytes = vec![0x80, 0x01];
+            bytes.extend([7; 128]);
+            bytes
+        });
+        round_trip(PrefixedString::<BigEndian<u32>>::from("hi".to_string()), &[
+            0, 0, 0, 2, b'h', b'i',
+        ]);
+        round_trip(FixedString::<4>::from("hi".to_string()), &[
+            b'h', b'i', 0, 0,
+        ]);
+        round_trip(Padded::<3, u8>(1), &[1, 0, 0]);
+        round_trip(PrePadded::<1, u8>(1), &[0, 1]);
+        round_trip(PostPadded::<1, u8>(1), &[1, 0]);
+        round_trip(RemainderVec(vec![1u16, 2]), &[1, 0, 2, 0]);
+        round_trip(RemainderString("hi".to_string()), b"hi");
+        round_trip(RemainderOption::<u8>(None), &[]);
+        round_trip(ZeroableOption::<u16>(None), &[0, 0]);
+        round_trip(ZeroableOption(Some(5u16)), &[5, 0]);
+
+        assert!(borsh::to_vec(&FixedString::<1>::from("hi".to_string())).is_err());
+        assert!(PrefixedString::<u64>::try_from_slice(&[u8::MAX; 8]).is_err());
+    }
+}
@@ -0,0 +1,29 @@
+use {borsh::BorshDeserialize, std::io};
+
+/// Tag prefixing the instruction data of Anchor's `emit_cpi!` self-CPI.
+pub const EVENT_IX_TAG_LE: [u8; 8] = [0xe4, 0x45, 0xa5, 0x2e, 0x51, 0xcb, 0x9a, 0x1d];
+
+/// Program event stored as a discriminator prefix followed by Borsh data.
+///
+/// Implemented by every generated event struct.
+pub trait EventDeserialize: BorshDeserialize {
+    /// Bytes identifying this event type at the start of the event data.
+    const DISCRIMINATOR: &'static [u8];
+
+    /// Decodes event data, e.g. a `Program data:` log, verifying and skipping
+    /// the discriminator.
+    fn try_from_event_data(data: &[u8]) -> io::Result<Self> {
+        let mut body = data.strip_prefix(Self::DISCRIMINATOR).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidData, "event discriminator mismatch")
+        })?;
+        Self::deserialize(&mut body)
+    }
+
+    /// Decodes the instruction data of an `emit_cpi!` self-CPI.
+    fn try_from_cpi_event_data(data: &[u8]) -> io::Result<Self> {
+        let data = data.strip_prefix(&EVENT_IX_TAG_LE).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidData, "missing event instruction tag")
+        })?;
+        Self::try_from_event_data(data)
+    }
+}
@@ -0,0 +1,212 @@
+//! Serde adapters for JSON-friendly representations of generated types.
+//!
+//! Used through `#[serde(with = "...")]` attributes emitted by the generator
+//! when human-readable serde is enabled: pubkeys become base58 strings, byte
+//! arrays become hex strings and 64/128-bit integers become decimal strings so
+//! they survive JavaScript number precision.
+
+use {
+    serde::{Deserialize, Deserializer, Serializer, de::Error},
+    std::{fmt::Display, str::FromStr},
+};
+
+/// `T` as its [`Display`] string, parsed back with [`FromStr`].
+///
+/// Covers `Pubkey` (base58) and large integers.
+pub mod display_from_str {
+    use super::*;
+
+    pub fn serialize<T: Display, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(value)
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: FromStr,
+        T::Err: Display,
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(D::Error::custom)
+    }
+}
+
+/// [`display_from_str`] for `Option<T>`.
+pub mod option_display_from_str {
+    use super::*;
+
+    pub fn serialize<T: Display, S: Serializer>(
+        value: &Option<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(v) => serializer.collect_str(v),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        T: FromStr,
+        T::Err: Display,
+        D: Deserializer<'de>,
+    {
+        Option::<String>::deserialize(deserializer)?
+            .map(|s| s.parse().map_err(D::Error::custom))
+            .transpose()
+    }
+}
+
+/// [`display_from_str`] for `Vec<T>`.
+pub mod vec_display_from_str {
+    use super::*;
+
+    pub fn serialize<T: Display, S: Serializer>(
+        value: &[T],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(value.iter().map(|v| v.to_string()))
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Vec<T>, D::Error>
+    where
+        T: FromStr,
+        T::Err: Display,
+        D: Deserializer<'de>,
+    {
+        Vec::<String>::deserialize(deserializer)?
+            .iter()
+            .map(|s| s.parse().map_err(D::Error::custom))
+            .collect()
+    }
+}
+
+/// [`display_from_str`] for `[T; N]`.
+pub mod array_display_from_str {
+    use super::*;
+
+    pub fn serialize<const N: usize, T: Display, S: Serializer>(
+        value: &[T; N],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        super::vec_display_from_str::serialize(value, serializer)
+    }
+
+    pub fn deserialize<'de, const N: usize, T, D>(deserializer: D) -> Result<[T; N], D::Error>
+    where
+        T: FromStr,
+        T::Err: Display,
+        D: Deserializer<'de>,
+    {
+        let values = super::vec_display_from_str::deserialize(deserializer)?;
+        let len = values.len();
+        values
+            .try_into()
+            .map_err(|_| D::Error::invalid_length(len, &format!("{N} elements").as_str()))
+    }
+}
+
+/// `[u8; N]` as a hex string.
+pub mod hex_array {
+    use super::*;
+
+    pub fn serialize<const N: usize, S: Serializer>(
+        value: &[u8; N],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&hex::encode(value))
+    }
+
+    pub fn deserialize<'de, const N: usize, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<[u8; N], D::Error> {
+        let s = String::deserialize(deserializer)?;
+        let mut bytes = [0u8; N];
+        hex::decode_to_slice(s, &mut bytes).map_err(D::Error::custom)?;
+        Ok(bytes)
+    }
+}
+
+/// `Vec<u8>` as a hex string.
+pub mod hex_vec {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(value: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&hex::encode(value))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        hex::decode(s).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        serde::{Deserialize, Serialize},
+        solana_pubkey::Pubkey,
+    };
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Readable {
+        #[serde(with = "super::display_from_str")]
+        owner: Pubkey,
+        #[serde(with = "super::option_display_from_str")]
+        delegate: Option<Pubkey>,
+        #[serde(with = "super::vec_display_from_str")]
+        signers: Vec<Pubkey>,
+        #[serde(with = "super::display_from_str")]
+        amount: u128,
+        #[serde(with = "super::hex_array")]
+        hash: [u8; 4],
+        #[serde(with = "super::hex_vec")]
+        memo: Vec<u8>,
+        #[serde(with = "super::array_display_from_str")]
+        limits: [u64; 2],
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    enum Transfer {
+        To(
+            #[serde(with = "super::display_from_str")] Pubkey,
+            #[serde(with = "super::display_from_str")] u64,
+        ),
+    }
+
+    #[test]
+    fn test_human_readable_round_trip() {
+        let owner = Pubkey::new_unique();
+        let value = Readable {
+            owner,
+            delegate: None,
+            signers: vec![owner],
+            amount: u128::MAX,
+            hash: [0xde, 0xad, 0xbe, 0xef],
+            memo: b"hi".to_vec(),
+            limits: [1, u64::MAX],
+        };
+        let json = serde_json::to_value(&value).unwrap();
+        assert_eq!(json["owner"], owner.to_string());
+        assert_eq!(json["delegate"], serde_json::Value::Null);
+        assert_eq!(json["signers"][0], owner.to_string());
+        assert_eq!(json["amount"], u128::MAX.to_string());
+        assert_eq!(json["hash"], "deadbeef");
+        assert_eq!(json["memo"], "6869");
+        assert_eq!(json["limits"][1], u64::MAX.to_string());
+        assert_eq!(value, serde_json::from_value(json).unwrap());
+    }
+
+    #[test]
+    fn test_human_readable_tuple_variant_round_trip() {
+        let owner = Pubkey::new_unique();
+        let value = Transfer::To(owner, u64::MAX);
+        let json = serde_json::to_value(&value).unwrap();
+        assert_eq!(json["To"][0], owner.to_string());
+        assert_eq!(json["To"][1], u64::MAX.to_string());
+        assert_eq!(value, serde_json::from_value(json).unwrap());
+    }
+}
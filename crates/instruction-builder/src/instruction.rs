@@ -5,7 +5,8 @@ use {
 };
 
 /// Builder for creating Solana [`Instruction`]s with Borsh-serialized data.
-#[derive(bon::Builder, Debug, Clone)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "builder", derive(bon::Builder))]
 pub struct InstructionBuilder<T: BorshSerialize> {
     pub params: T,
     pub program_id: Pubkey,
@@ -13,6 +14,14 @@ pub struct InstructionBuilder<T: BorshSerialize> {
 }
 
 impl<T: BorshSerialize> InstructionBuilder<T> {
+    pub fn new(program_id: Pubkey, accounts: Vec<AccountMeta>, params: T) -> Self {
+        Self {
+            params,
+            program_id,
+            accounts,
+        }
+    }
+
     /// Appends additional accounts to the instruction.
     pub fn remaining_accounts(mut self, mut account: Vec<AccountMeta>) -> Self {
         self.accounts.append(&mut account);
@@ -6,8 +6,16 @@ use {
     solana_pubkey::Pubkey,
 };
 
+mod account;
+mod event;
 mod instruction;
-pub use instruction::*;
+pub use {account::*, event::*, instruction::*};
+
+#[cfg(feature = "client")]
+pub mod client;
+pub mod codec;
+#[cfg(feature = "serde")]
+pub mod human_readable;
 
 /// Derives a PDA and returns an [`AccountMeta`].
 ///
@@ -91,11 +99,7 @@ mod tests {
         let memo: MemoData = "Test instruction creation".into();
         let accounts = vec![AccountMeta::new_readonly(Pubkey::new_unique(), true)];
 
-        let builder = InstructionBuilder::builder()
-            .program_id(spl_memo_interface::v3::id())
-            .accounts(accounts.clone())
-            .params(memo)
-            .build();
+        let builder = InstructionBuilder::new(spl_memo_interface::v3::id(), accounts.clone(), memo);
 
         let instruction = builder.instruction();
         assert_eq!(instruction.program_id, spl_memo_interface::v3::id());
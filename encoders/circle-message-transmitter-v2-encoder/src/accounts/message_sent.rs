@@ -1,18 +1,21 @@
+// @generated by nitrogen, do not edit.
 #[derive(
     Debug,
     borsh::BorshSerialize,
     borsh::BorshDeserialize,
-    bon::Builder,
-    serde::Serialize,
-    serde::Deserialize,
     PartialEq,
     Default,
     Eq,
     Clone,
     Hash,
 )]
+#[cfg_attr(feature = "builder", derive(bon::Builder))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MessageSent {
     pub rent_payer: solana_pubkey::Pubkey,
     pub created_at: i64,
     pub message: Vec<u8>,
 }
+impl nitrogen_instruction_builder::AccountDeserialize for MessageSent {
+    const DISCRIMINATOR: &'static [u8] = &[131, 100, 133, 56, 166, 225, 151, 60];
+}
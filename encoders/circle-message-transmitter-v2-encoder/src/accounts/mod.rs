@@ -1,12 +1,43 @@
+// @generated by nitrogen, do not edit.
 pub mod message_sent;
 pub use message_sent::*;
 pub mod message_transmitter;
 pub use message_transmitter::*;
 pub mod used_nonce;
 pub use used_nonce::*;
-
 pub enum MessageTransmitterV2Account {
     MessageSent(message_sent::MessageSent),
     MessageTransmitter(message_transmitter::MessageTransmitter),
     UsedNonce(used_nonce::UsedNonce),
 }
+impl MessageTransmitterV2Account {
+    /// Decodes account data as the first account type it matches.
+    pub fn try_from_account_data(data: &[u8]) -> std::io::Result<Self> {
+        use nitrogen_instruction_builder::AccountDeserialize;
+        if message_sent::MessageSent::matches_account_data(data) {
+            return Ok(
+                Self::MessageSent(
+                    message_sent::MessageSent::try_from_account_data(data)?,
+                ),
+            );
+        }
+        if message_transmitter::MessageTransmitter::matches_account_data(data) {
+            return Ok(
+                Self::MessageTransmitter(
+                    message_transmitter::MessageTransmitter::try_from_account_data(data)?,
+                ),
+            );
+        }
+        if used_nonce::UsedNonce::matches_account_data(data) {
+            return Ok(
+                Self::UsedNonce(used_nonce::UsedNonce::try_from_account_data(data)?),
+            );
+        }
+        Err(
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "unknown account discriminator",
+            ),
+        )
+    }
+}
@@ -1,16 +1,19 @@
+// @generated by nitrogen, do not edit.
 #[derive(
     Debug,
     borsh::BorshSerialize,
     borsh::BorshDeserialize,
-    bon::Builder,
-    serde::Serialize,
-    serde::Deserialize,
     PartialEq,
     Default,
     Eq,
     Clone,
     Hash,
 )]
+#[cfg_attr(feature = "builder", derive(bon::Builder))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UsedNonce {
     pub is_used: bool,
 }
+impl nitrogen_instruction_builder::AccountDeserialize for UsedNonce {
+    const DISCRIMINATOR: &'static [u8] = &[212, 222, 157, 252, 130, 71, 179, 238];
+}
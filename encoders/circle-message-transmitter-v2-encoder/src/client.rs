@@ -0,0 +1,64 @@
+// @generated by nitrogen, do not edit.
+use {
+    crate::accounts::*,
+    nitrogen_instruction_builder::{
+        AccountDeserialize, client::{self, AccountFetcher, ClientResult, RpcFilterType},
+    },
+    solana_pubkey::Pubkey,
+};
+/// Fetches and decodes accounts owned by the program.
+pub struct MessageTransmitterV2Client<R: AccountFetcher> {
+    pub rpc: R,
+    pub program_id: Pubkey,
+}
+impl<R: AccountFetcher> MessageTransmitterV2Client<R> {
+    pub fn new(rpc: R) -> Self {
+        Self { rpc, program_id: crate::ID }
+    }
+    /// Targets a deployment of the program at `program_id` instead of
+    /// [`crate::ID`].
+    pub fn with_program_id(mut self, program_id: Pubkey) -> Self {
+        self.program_id = program_id;
+        self
+    }
+    /// Fetches several accounts of the same type, `None` for missing accounts.
+    pub async fn fetch_multiple<T: AccountDeserialize>(
+        &self,
+        addresses: &[Pubkey],
+    ) -> ClientResult<Vec<Option<T>>> {
+        client::fetch_multiple_accounts(&self.rpc, addresses).await
+    }
+    pub async fn fetch_message_sent(
+        &self,
+        address: &Pubkey,
+    ) -> ClientResult<MessageSent> {
+        client::fetch_account(&self.rpc, address).await
+    }
+    pub async fn fetch_all_message_sent(
+        &self,
+        filters: Vec<RpcFilterType>,
+    ) -> ClientResult<Vec<(Pubkey, MessageSent)>> {
+        client::fetch_program_accounts(&self.rpc, &self.program_id, filters).await
+    }
+    pub async fn fetch_message_transmitter(
+        &self,
+        address: &Pubkey,
+    ) -> ClientResult<MessageTransmitter> {
+        client::fetch_account(&self.rpc, address).await
+    }
+    pub async fn fetch_all_message_transmitter(
+        &self,
+        filters: Vec<RpcFilterType>,
+    ) -> ClientResult<Vec<(Pubkey, MessageTransmitter)>> {
+        client::fetch_program_accounts(&self.rpc, &self.program_id, filters).await
+    }
+    pub async fn fetch_used_nonce(&self, address: &Pubkey) -> ClientResult<UsedNonce> {
+        client::fetch_account(&self.rpc, address).await
+    }
+    pub async fn fetch_all_used_nonce(
+        &self,
+        filters: Vec<RpcFilterType>,
+    ) -> ClientResult<Vec<(Pubkey, UsedNonce)>> {
+        client::fetch_program_accounts(&self.rpc, &self.program_id, filters).await
+    }
+}
@@ -1,9 +1,6 @@
 use {
     crate::{accounts::MessageSent, types::ReclaimEventAccountParams},
-    solana_account::Account,
-    solana_client::nonblocking::rpc_client::RpcClient,
     solana_pubkey::Pubkey,
-    solana_rpc_client_api::client_error::Result as ClientResult,
     std::{
         fmt::{Display, Formatter},
         time::Duration,
@@ -11,23 +8,7 @@ use {
 };
 
 pub const TOKEN_MINTER_PROGRAM_ID: Pubkey =
-    solana_pubkey::pubkey!("CCTPV2vPZJS2u2BBsUoscuikbYjnpFmbFsvVuJdgUMQe");
-
-#[async_trait::async_trait]
-pub trait ReclaimAccountRpcState: Send + Sync {
-    async fn get_reclaim_accounts(&self, owner: &Pubkey) -> ClientResult<Vec<(Pubkey, Account)>>;
-    async fn get_reclaim_account_signature(&self, account: &Pubkey)
-    -> ClientResult<Option<String>>;
-}
-
-/// The fee account is owned by Circle and is used to pay for CCTP fees.
-#[async_trait::async_trait]
-pub trait FeeRecipientFetcher: Send + Sync {
-    async fn get_fee_recipient_token_account(
-        &self,
-        circle_usdc_address: &Pubkey,
-    ) -> ClientResult<Pubkey>;
-}
+    nitrogen_circle_token_messenger_minter_v2_encoder::ID;
 
 pub struct ReclaimAccountStatus {
     pub owner: Pubkey,
@@ -54,6 +35,7 @@ impl Display for ReclaimAccountStatus {
 }
 
 impl ReclaimAccountStatus {
+    #[cfg(feature = "client")]
     fn new(owner: Pubkey) -> Self {
         Self {
             owner,
@@ -104,6 +86,7 @@ impl Display for ReclaimAccount {
 }
 
 impl ReclaimAccount {
+    #[cfg(feature = "client")]
     fn new(
         address: Pubkey,
         account: MessageSent,
@@ -132,16 +115,15 @@ impl ReclaimAccount {
         &self,
         attestation_message: AttestationMessage,
     ) -> solana_instruction::Instruction {
-        crate::instructions::reclaim_event_account(
-            ReclaimEventAccountParams::builder()
-                .attestation(attestation_message.0)
-                .destination_message(attestation_message.1)
-                .build(),
-        )
+        crate::instructions::reclaim_event_account(ReclaimEventAccountParams {
+            attestation: attestation_message.0,
+            destination_message: attestation_message.1,
+        })
         .accounts(self.account.rent_payer, self.address)
         .instruction()
     }
 }
 
 pub mod receive_message_helpers;
+#[cfg(feature = "client")]
 pub mod reclaim_event_account_helpers;
@@ -1,83 +1,39 @@
 use {
-    super::FeeRecipientFetcher,
     crate::{TOKEN_MINTER_PROGRAM_ID, instructions::receive_message, types::ReceiveMessageParams},
     alloy_primitives::FixedBytes,
-    borsh::BorshDeserialize,
-    nitrogen_instruction_builder::{InstructionBuilder, derive_pda},
+    nitrogen_circle_token_messenger_minter_v2_encoder::accounts::TokenMessenger,
+    nitrogen_instruction_builder::{AccountDeserialize, InstructionBuilder, derive_pda},
     solana_instruction::AccountMeta,
     solana_pubkey::Pubkey,
-    solana_rpc_client::nonblocking::rpc_client::RpcClient,
-    solana_rpc_client_api::client_error::Result as ClientResult,
-    std::fmt::{Debug, Display},
     tracing::debug,
 };
 
-#[async_trait::async_trait]
-impl<T: AsRef<RpcClient> + Send + Sync> FeeRecipientFetcher for T {
-    async fn get_fee_recipient_token_account(
-        &self,
-        circle_usdc_address: &Pubkey,
-    ) -> ClientResult<Pubkey> {
-        let token_messenger_account =
-            Pubkey::find_program_address(&[b"token_messenger"], &super::TOKEN_MINTER_PROGRAM_ID).0;
-        debug!("lookup up token_messenger_account {token_messenger_account}");
-        let account_data = self
-            .as_ref()
-            .get_account_data(&token_messenger_account)
-            .await?;
-        let fee_recipient = decode_fee_recipient_account(&account_data)?;
-        let token_account = spl_associated_token_account::get_associated_token_address(
-            &fee_recipient,
-            circle_usdc_address,
-        );
-        debug!("fee recipient token account {token_account}");
-        Ok(token_account)
-    }
-}
-
-// TODO maybe depend on token_minter crate?
-#[derive(Debug, borsh::BorshSerialize, borsh::BorshDeserialize)]
-struct TokenMessenger {
-    pub denylister: solana_pubkey::Pubkey,
-    pub owner: solana_pubkey::Pubkey,
-    pub pending_owner: solana_pubkey::Pubkey,
-    pub message_body_version: u32,
-    pub authority_bump: u8,
-    pub fee_recipient: solana_pubkey::Pubkey,
-    pub min_fee_controller: solana_pubkey::Pubkey,
-    pub min_fee: u32,
-}
+#[cfg(feature = "client")]
+use nitrogen_instruction_builder::client::{self, AccountFetcher, ClientResult};
 
-impl Display for TokenMessenger {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "denylister={},owner={},pending_owner={},message_body_version={},fee_recipient={},\
-             min_fee_controller={},min_fee={}",
-            self.denylister,
-            self.owner,
-            self.pending_owner,
-            self.message_body_version,
-            self.fee_recipient,
-            self.min_fee_controller,
-            self.min_fee
-        )
-    }
-}
-
-#[allow(clippy::result_large_err)]
-pub fn decode_fee_recipient_account(account_data: &[u8]) -> ClientResult<Pubkey> {
-    let messenger = TokenMessenger::try_from_slice(&account_data[8..])?;
-    debug!("TokenMessenger: {messenger}");
+pub fn decode_fee_recipient_account(account_data: &[u8]) -> std::io::Result<Pubkey> {
+    let messenger = TokenMessenger::try_from_account_data(account_data)?;
+    debug!("TokenMessenger: {messenger:?}");
     Ok(messenger.fee_recipient)
 }
 
-pub async fn fee_recipient_token_account<T: FeeRecipientFetcher>(
-    rpc: &T,
+/// The token account of the fee recipient, which is owned by Circle and is
+/// used to pay for CCTP fees.
+#[cfg(feature = "client")]
+pub async fn fee_recipient_token_account<R: AccountFetcher + ?Sized>(
+    rpc: &R,
     circle_usdc_address: &Pubkey,
 ) -> ClientResult<Pubkey> {
-    rpc.get_fee_recipient_token_account(circle_usdc_address)
-        .await
+    let token_messenger_account =
+        Pubkey::find_program_address(&[b"token_messenger"], &TOKEN_MINTER_PROGRAM_ID).0;
+    debug!("lookup up token_messenger_account {token_messenger_account}");
+    let messenger: TokenMessenger = client::fetch_account(rpc, &token_messenger_account).await?;
+    let token_account = spl_associated_token_account::get_associated_token_address(
+        &messenger.fee_recipient,
+        circle_usdc_address,
+    );
+    debug!("fee recipient token account {token_account}");
+    Ok(token_account)
 }
 
 pub fn recv_from_attestation(
@@ -89,12 +45,10 @@ pub fn recv_from_attestation(
     let used_nonce =
         Pubkey::find_program_address(&[b"used_nonce", &message[12..12 + 32]], &crate::ID).0;
 
-    receive_message(
-        ReceiveMessageParams::builder()
-            .attestation(attestation)
-            .message(message)
-            .build(),
-    )
+    receive_message(ReceiveMessageParams {
+        message,
+        attestation,
+    })
     .accounts(
         destination_owner,
         destination_owner,
@@ -1,15 +1,14 @@
+// @generated by nitrogen, do not edit.
+use super::super::types::*;
 use {
-    super::super::types::*,
     nitrogen_instruction_builder::{InstructionBuilder, derive_pda},
-    solana_instruction::AccountMeta,
-    solana_pubkey::Pubkey,
+    solana_instruction::AccountMeta, solana_pubkey::Pubkey,
 };
-
-#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash)]
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReceiveMessage {
     pub params: ReceiveMessageParams,
 }
-
 impl borsh::BorshSerialize for ReceiveMessage {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         writer.write_all(&[38, 144, 127, 225, 31, 225, 238, 25])?;
@@ -17,7 +16,6 @@ impl borsh::BorshSerialize for ReceiveMessage {
         Ok(())
     }
 }
-
 impl ReceiveMessage {
     pub fn accounts(
         self,
@@ -27,28 +25,108 @@ impl ReceiveMessage {
         used_nonce: Pubkey,
         receiver: Pubkey,
         program: Pubkey,
+    ) -> InstructionBuilder<Self> {
+        self.accounts_with_program_id(
+            crate::ID,
+            payer,
+            caller,
+            message_transmitter,
+            used_nonce,
+            receiver,
+            program,
+        )
+    }
+    /// Same as [`Self::accounts`], for a deployment of the program at
+    /// `__program_id`. PDAs are derived from `__program_id` and accounts fixed
+    /// to the program's own address are replaced by it.
+    pub fn accounts_with_program_id(
+        self,
+        __program_id: Pubkey,
+        payer: Pubkey,
+        caller: Pubkey,
+        message_transmitter: Pubkey,
+        used_nonce: Pubkey,
+        receiver: Pubkey,
+        program: Pubkey,
     ) -> InstructionBuilder<Self> {
         let mut accounts: Vec<AccountMeta> = Vec::with_capacity(9);
         accounts.push(AccountMeta::new(payer, true));
         accounts.push(AccountMeta::new_readonly(caller, true));
-        accounts.push(derive_pda(
-            &[b"message_transmitter_authority", receiver.as_ref()],
-            &crate::ID,
-            true,
-        ));
+        accounts
+            .push(
+                derive_pda(
+                    &[b"message_transmitter_authority", receiver.as_ref()],
+                    &__program_id,
+                    true,
+                ),
+            );
         accounts.push(AccountMeta::new_readonly(message_transmitter, false));
         accounts.push(AccountMeta::new(used_nonce, false));
         accounts.push(AccountMeta::new_readonly(receiver, false));
-        accounts.push(AccountMeta::new_readonly(
-            solana_pubkey::pubkey!("11111111111111111111111111111111"),
-            false,
-        ));
-        accounts.push(derive_pda(&[b"__event_authority"], &crate::ID, true));
+        accounts
+            .push(
+                AccountMeta::new_readonly(
+                    solana_pubkey::pubkey!("11111111111111111111111111111111"),
+                    false,
+                ),
+            );
+        accounts.push(derive_pda(&[b"__event_authority"], &__program_id, true));
         accounts.push(AccountMeta::new_readonly(program, false));
-        InstructionBuilder::builder()
-            .accounts(accounts)
-            .program_id(crate::ID)
-            .params(self)
-            .build()
+        InstructionBuilder::new(__program_id, accounts, self)
+    }
+}
+/// Invokes [`ReceiveMessage`] from another program. Accounts are
+/// listed in IDL order.
+#[cfg(feature = "cpi")]
+pub struct ReceiveMessageCpi<'a, 'info> {
+    /// The program being invoked.
+    pub __program: &'a solana_account_info::AccountInfo<'info>,
+    pub payer: &'a solana_account_info::AccountInfo<'info>,
+    pub caller: &'a solana_account_info::AccountInfo<'info>,
+    pub authority_pda: &'a solana_account_info::AccountInfo<'info>,
+    pub message_transmitter: &'a solana_account_info::AccountInfo<'info>,
+    pub used_nonce: &'a solana_account_info::AccountInfo<'info>,
+    pub receiver: &'a solana_account_info::AccountInfo<'info>,
+    pub system_program: &'a solana_account_info::AccountInfo<'info>,
+    pub event_authority: &'a solana_account_info::AccountInfo<'info>,
+    pub program: &'a solana_account_info::AccountInfo<'info>,
+    pub __data: ReceiveMessage,
+}
+#[cfg(feature = "cpi")]
+impl<'a, 'info> ReceiveMessageCpi<'a, 'info> {
+    pub fn invoke(&self) -> solana_program_error::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program_error::ProgramResult {
+        let mut accounts: Vec<AccountMeta> = Vec::with_capacity(9);
+        let mut account_infos = Vec::with_capacity(10);
+        account_infos.push(self.__program.clone());
+        accounts.push(AccountMeta::new(*self.payer.key, true));
+        account_infos.push(self.payer.clone());
+        accounts.push(AccountMeta::new_readonly(*self.caller.key, true));
+        account_infos.push(self.caller.clone());
+        accounts.push(AccountMeta::new_readonly(*self.authority_pda.key, false));
+        account_infos.push(self.authority_pda.clone());
+        accounts.push(AccountMeta::new_readonly(*self.message_transmitter.key, false));
+        account_infos.push(self.message_transmitter.clone());
+        accounts.push(AccountMeta::new(*self.used_nonce.key, false));
+        account_infos.push(self.used_nonce.clone());
+        accounts.push(AccountMeta::new_readonly(*self.receiver.key, false));
+        account_infos.push(self.receiver.clone());
+        accounts.push(AccountMeta::new_readonly(*self.system_program.key, false));
+        account_infos.push(self.system_program.clone());
+        accounts.push(AccountMeta::new_readonly(*self.event_authority.key, false));
+        account_infos.push(self.event_authority.clone());
+        accounts.push(AccountMeta::new_readonly(*self.program.key, false));
+        account_infos.push(self.program.clone());
+        let instruction = solana_instruction::Instruction::new_with_borsh(
+            *self.__program.key,
+            &self.__data,
+            accounts,
+        );
+        solana_cpi::invoke_signed(&instruction, &account_infos, signers_seeds)
     }
 }
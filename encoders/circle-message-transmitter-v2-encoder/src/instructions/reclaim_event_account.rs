@@ -1,15 +1,14 @@
+// @generated by nitrogen, do not edit.
+use super::super::types::*;
 use {
-    super::super::types::*,
     nitrogen_instruction_builder::{InstructionBuilder, derive_pda},
-    solana_instruction::AccountMeta,
-    solana_pubkey::Pubkey,
+    solana_instruction::AccountMeta, solana_pubkey::Pubkey,
 };
-
-#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash)]
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReclaimEventAccount {
     pub params: ReclaimEventAccountParams,
 }
-
 impl borsh::BorshSerialize for ReclaimEventAccount {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         writer.write_all(&[94, 198, 180, 159, 131, 236, 15, 174])?;
@@ -17,21 +16,64 @@ impl borsh::BorshSerialize for ReclaimEventAccount {
         Ok(())
     }
 }
-
 impl ReclaimEventAccount {
     pub fn accounts(
         self,
         payee: Pubkey,
         message_sent_event_data: Pubkey,
+    ) -> InstructionBuilder<Self> {
+        self.accounts_with_program_id(crate::ID, payee, message_sent_event_data)
+    }
+    /// Same as [`Self::accounts`], for a deployment of the program at
+    /// `__program_id`. PDAs are derived from `__program_id` and accounts fixed
+    /// to the program's own address are replaced by it.
+    pub fn accounts_with_program_id(
+        self,
+        __program_id: Pubkey,
+        payee: Pubkey,
+        message_sent_event_data: Pubkey,
     ) -> InstructionBuilder<Self> {
         let mut accounts: Vec<AccountMeta> = Vec::with_capacity(3);
         accounts.push(AccountMeta::new(payee, true));
-        accounts.push(derive_pda(&[b"message_transmitter"], &crate::ID, false));
+        accounts.push(derive_pda(&[b"message_transmitter"], &__program_id, false));
         accounts.push(AccountMeta::new(message_sent_event_data, false));
-        InstructionBuilder::builder()
-            .accounts(accounts)
-            .program_id(crate::ID)
-            .params(self)
-            .build()
+        InstructionBuilder::new(__program_id, accounts, self)
+    }
+}
+/// Invokes [`ReclaimEventAccount`] from another program. Accounts are
+/// listed in IDL order.
+#[cfg(feature = "cpi")]
+pub struct ReclaimEventAccountCpi<'a, 'info> {
+    /// The program being invoked.
+    pub __program: &'a solana_account_info::AccountInfo<'info>,
+    pub payee: &'a solana_account_info::AccountInfo<'info>,
+    pub message_transmitter: &'a solana_account_info::AccountInfo<'info>,
+    pub message_sent_event_data: &'a solana_account_info::AccountInfo<'info>,
+    pub __data: ReclaimEventAccount,
+}
+#[cfg(feature = "cpi")]
+impl<'a, 'info> ReclaimEventAccountCpi<'a, 'info> {
+    pub fn invoke(&self) -> solana_program_error::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program_error::ProgramResult {
+        let mut accounts: Vec<AccountMeta> = Vec::with_capacity(3);
+        let mut account_infos = Vec::with_capacity(4);
+        account_infos.push(self.__program.clone());
+        accounts.push(AccountMeta::new(*self.payee.key, true));
+        account_infos.push(self.payee.clone());
+        accounts.push(AccountMeta::new(*self.message_transmitter.key, false));
+        account_infos.push(self.message_transmitter.clone());
+        accounts.push(AccountMeta::new(*self.message_sent_event_data.key, false));
+        account_infos.push(self.message_sent_event_data.clone());
+        let instruction = solana_instruction::Instruction::new_with_borsh(
+            *self.__program.key,
+            &self.__data,
+            accounts,
+        );
+        solana_cpi::invoke_signed(&instruction, &account_infos, signers_seeds)
     }
 }
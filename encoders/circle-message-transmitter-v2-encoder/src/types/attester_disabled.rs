@@ -1,15 +0,0 @@
-#[derive(
-    Debug,
-    borsh::BorshSerialize,
-    borsh::BorshDeserialize,
-    bon::Builder,
-    serde::Serialize,
-    serde::Deserialize,
-    PartialEq,
-    Eq,
-    Clone,
-    Hash,
-)]
-pub struct AttesterDisabled {
-    pub attester: solana_pubkey::Pubkey,
-}
@@ -1,15 +0,0 @@
-#[derive(
-    Debug,
-    borsh::BorshSerialize,
-    borsh::BorshDeserialize,
-    bon::Builder,
-    serde::Serialize,
-    serde::Deserialize,
-    PartialEq,
-    Eq,
-    Clone,
-    Hash,
-)]
-pub struct EnableAttesterParams {
-    pub new_attester: solana_pubkey::Pubkey,
-}
@@ -1,15 +1,15 @@
+// @generated by nitrogen, do not edit.
 #[derive(
     Debug,
     borsh::BorshSerialize,
     borsh::BorshDeserialize,
-    bon::Builder,
-    serde::Serialize,
-    serde::Deserialize,
     PartialEq,
     Eq,
     Clone,
-    Hash,
+    Hash
 )]
+#[cfg_attr(feature = "builder", derive(bon::Builder))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MessageSent {
     pub rent_payer: solana_pubkey::Pubkey,
     pub created_at: i64,
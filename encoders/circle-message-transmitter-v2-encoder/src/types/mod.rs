@@ -1,56 +1,11 @@
-pub mod accept_ownership_params;
-pub use accept_ownership_params::*;
-pub mod attester_disabled;
-pub use attester_disabled::*;
-pub mod attester_enabled;
-pub use attester_enabled::*;
-pub mod attester_manager_updated;
-pub use attester_manager_updated::*;
-pub mod disable_attester_params;
-pub use disable_attester_params::*;
-pub mod enable_attester_params;
-pub use enable_attester_params::*;
-pub mod initialize_params;
-pub use initialize_params::*;
-pub mod max_message_body_size_updated;
-pub use max_message_body_size_updated::*;
-pub mod message_received;
-pub use message_received::*;
+// @generated by nitrogen, do not edit.
 pub mod message_sent;
 pub use message_sent::*;
 pub mod message_transmitter;
 pub use message_transmitter::*;
-pub mod ownership_transfer_started;
-pub use ownership_transfer_started::*;
-pub mod ownership_transferred;
-pub use ownership_transferred::*;
-pub mod pause;
-pub use pause::*;
-pub mod pause_params;
-pub use pause_params::*;
-pub mod pauser_changed;
-pub use pauser_changed::*;
 pub mod receive_message_params;
 pub use receive_message_params::*;
 pub mod reclaim_event_account_params;
 pub use reclaim_event_account_params::*;
-pub mod send_message_params;
-pub use send_message_params::*;
-pub mod set_max_message_body_size_params;
-pub use set_max_message_body_size_params::*;
-pub mod set_signature_threshold_params;
-pub use set_signature_threshold_params::*;
-pub mod signature_threshold_updated;
-pub use signature_threshold_updated::*;
-pub mod transfer_ownership_params;
-pub use transfer_ownership_params::*;
-pub mod unpause;
-pub use unpause::*;
-pub mod unpause_params;
-pub use unpause_params::*;
-pub mod update_attester_manager_params;
-pub use update_attester_manager_params::*;
-pub mod update_pauser_params;
-pub use update_pauser_params::*;
 pub mod used_nonce;
 pub use used_nonce::*;
@@ -1,16 +0,0 @@
-#[derive(
-    Debug,
-    borsh::BorshSerialize,
-    borsh::BorshDeserialize,
-    bon::Builder,
-    serde::Serialize,
-    serde::Deserialize,
-    PartialEq,
-    Eq,
-    Clone,
-    Hash,
-)]
-pub struct OwnershipTransferStarted {
-    pub previous_owner: solana_pubkey::Pubkey,
-    pub new_owner: solana_pubkey::Pubkey,
-}
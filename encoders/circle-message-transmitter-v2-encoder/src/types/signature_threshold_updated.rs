@@ -1,16 +0,0 @@
-#[derive(
-    Debug,
-    borsh::BorshSerialize,
-    borsh::BorshDeserialize,
-    bon::Builder,
-    serde::Serialize,
-    serde::Deserialize,
-    PartialEq,
-    Eq,
-    Clone,
-    Hash,
-)]
-pub struct SignatureThresholdUpdated {
-    pub old_signature_threshold: u32,
-    pub new_signature_threshold: u32,
-}
@@ -1,16 +1,19 @@
+// @generated by nitrogen, do not edit.
 #[derive(
     Debug,
     borsh::BorshSerialize,
     borsh::BorshDeserialize,
-    bon::Builder,
-    serde::Serialize,
-    serde::Deserialize,
     PartialEq,
     Default,
     Eq,
     Clone,
     Hash,
 )]
+#[cfg_attr(feature = "builder", derive(bon::Builder))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DenylistedAccount {
     pub account: solana_pubkey::Pubkey,
 }
+impl nitrogen_instruction_builder::AccountDeserialize for DenylistedAccount {
+    const DISCRIMINATOR: &'static [u8] = &[186, 58, 212, 239, 102, 131, 157, 146];
+}
@@ -1,16 +1,16 @@
+// @generated by nitrogen, do not edit.
 #[derive(
     Debug,
     borsh::BorshSerialize,
     borsh::BorshDeserialize,
-    bon::Builder,
-    serde::Serialize,
-    serde::Deserialize,
     PartialEq,
     Default,
     Eq,
     Clone,
     Hash,
 )]
+#[cfg_attr(feature = "builder", derive(bon::Builder))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LocalToken {
     pub custody: solana_pubkey::Pubkey,
     pub mint: solana_pubkey::Pubkey,
@@ -22,3 +22,6 @@ pub struct LocalToken {
     pub bump: u8,
     pub custody_bump: u8,
 }
+impl nitrogen_instruction_builder::AccountDeserialize for LocalToken {
+    const DISCRIMINATOR: &'static [u8] = &[159, 131, 58, 170, 193, 84, 128, 182];
+}
@@ -1,16 +1,16 @@
+// @generated by nitrogen, do not edit.
 #[derive(
     Debug,
     borsh::BorshSerialize,
     borsh::BorshDeserialize,
-    bon::Builder,
-    serde::Serialize,
-    serde::Deserialize,
     PartialEq,
     Default,
     Eq,
     Clone,
     Hash,
 )]
+#[cfg_attr(feature = "builder", derive(bon::Builder))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MessageTransmitter {
     pub owner: solana_pubkey::Pubkey,
     pub pending_owner: solana_pubkey::Pubkey,
@@ -23,3 +23,6 @@ pub struct MessageTransmitter {
     pub enabled_attesters: Vec<solana_pubkey::Pubkey>,
     pub max_message_body_size: u64,
 }
+impl nitrogen_instruction_builder::AccountDeserialize for MessageTransmitter {
+    const DISCRIMINATOR: &'static [u8] = &[71, 40, 180, 142, 19, 203, 35, 252];
+}
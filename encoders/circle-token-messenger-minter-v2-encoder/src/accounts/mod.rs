@@ -1,3 +1,4 @@
+// @generated by nitrogen, do not edit.
 pub mod denylisted_account;
 pub use denylisted_account::*;
 pub mod local_token;
@@ -12,7 +13,6 @@ pub mod token_minter;
 pub use token_minter::*;
 pub mod token_pair;
 pub use token_pair::*;
-
 pub enum TokenMessengerMinterV2Account {
     DenylistedAccount(denylisted_account::DenylistedAccount),
     LocalToken(local_token::LocalToken),
@@ -22,3 +22,62 @@ pub enum TokenMessengerMinterV2Account {
     TokenMinter(token_minter::TokenMinter),
     TokenPair(token_pair::TokenPair),
 }
+impl TokenMessengerMinterV2Account {
+    /// Decodes account data as the first account type it matches.
+    pub fn try_from_account_data(data: &[u8]) -> std::io::Result<Self> {
+        use nitrogen_instruction_builder::AccountDeserialize;
+        if denylisted_account::DenylistedAccount::matches_account_data(data) {
+            return Ok(
+                Self::DenylistedAccount(
+                    denylisted_account::DenylistedAccount::try_from_account_data(data)?,
+                ),
+            );
+        }
+        if local_token::LocalToken::matches_account_data(data) {
+            return Ok(
+                Self::LocalToken(local_token::LocalToken::try_from_account_data(data)?),
+            );
+        }
+        if message_transmitter::MessageTransmitter::matches_account_data(data) {
+            return Ok(
+                Self::MessageTransmitter(
+                    message_transmitter::MessageTransmitter::try_from_account_data(data)?,
+                ),
+            );
+        }
+        if remote_token_messenger::RemoteTokenMessenger::matches_account_data(data) {
+            return Ok(
+                Self::RemoteTokenMessenger(
+                    remote_token_messenger::RemoteTokenMessenger::try_from_account_data(
+                        data,
+                    )?,
+                ),
+            );
+        }
+        if token_messenger::TokenMessenger::matches_account_data(data) {
+            return Ok(
+                Self::TokenMessenger(
+                    token_messenger::TokenMessenger::try_from_account_data(data)?,
+                ),
+            );
+        }
+        if token_minter::TokenMinter::matches_account_data(data) {
+            return Ok(
+                Self::TokenMinter(
+                    token_minter::TokenMinter::try_from_account_data(data)?,
+                ),
+            );
+        }
+        if token_pair::TokenPair::matches_account_data(data) {
+            return Ok(
+                Self::TokenPair(token_pair::TokenPair::try_from_account_data(data)?),
+            );
+        }
+        Err(
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "unknown account discriminator",
+            ),
+        )
+    }
+}
@@ -1,17 +1,20 @@
+// @generated by nitrogen, do not edit.
 #[derive(
     Debug,
     borsh::BorshSerialize,
     borsh::BorshDeserialize,
-    bon::Builder,
-    serde::Serialize,
-    serde::Deserialize,
     PartialEq,
     Default,
     Eq,
     Clone,
     Hash,
 )]
+#[cfg_attr(feature = "builder", derive(bon::Builder))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RemoteTokenMessenger {
     pub domain: u32,
     pub token_messenger: solana_pubkey::Pubkey,
 }
+impl nitrogen_instruction_builder::AccountDeserialize for RemoteTokenMessenger {
+    const DISCRIMINATOR: &'static [u8] = &[105, 115, 174, 34, 95, 233, 138, 252];
+}
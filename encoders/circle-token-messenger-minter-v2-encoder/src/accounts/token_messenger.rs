@@ -1,16 +1,16 @@
+// @generated by nitrogen, do not edit.
 #[derive(
     Debug,
     borsh::BorshSerialize,
     borsh::BorshDeserialize,
-    bon::Builder,
-    serde::Serialize,
-    serde::Deserialize,
     PartialEq,
     Default,
     Eq,
     Clone,
     Hash,
 )]
+#[cfg_attr(feature = "builder", derive(bon::Builder))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TokenMessenger {
     pub denylister: solana_pubkey::Pubkey,
     pub owner: solana_pubkey::Pubkey,
@@ -21,3 +21,6 @@ pub struct TokenMessenger {
     pub min_fee_controller: solana_pubkey::Pubkey,
     pub min_fee: u32,
 }
+impl nitrogen_instruction_builder::AccountDeserialize for TokenMessenger {
+    const DISCRIMINATOR: &'static [u8] = &[162, 4, 242, 52, 147, 243, 221, 96];
+}
@@ -1,19 +1,22 @@
+// @generated by nitrogen, do not edit.
 #[derive(
     Debug,
     borsh::BorshSerialize,
     borsh::BorshDeserialize,
-    bon::Builder,
-    serde::Serialize,
-    serde::Deserialize,
     PartialEq,
     Default,
     Eq,
     Clone,
     Hash,
 )]
+#[cfg_attr(feature = "builder", derive(bon::Builder))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TokenMinter {
     pub token_controller: solana_pubkey::Pubkey,
     pub pauser: solana_pubkey::Pubkey,
     pub paused: bool,
     pub bump: u8,
 }
+impl nitrogen_instruction_builder::AccountDeserialize for TokenMinter {
+    const DISCRIMINATOR: &'static [u8] = &[122, 133, 84, 63, 57, 159, 171, 206];
+}
@@ -1,19 +1,22 @@
+// @generated by nitrogen, do not edit.
 #[derive(
     Debug,
     borsh::BorshSerialize,
     borsh::BorshDeserialize,
-    bon::Builder,
-    serde::Serialize,
-    serde::Deserialize,
     PartialEq,
     Default,
     Eq,
     Clone,
     Hash,
 )]
+#[cfg_attr(feature = "builder", derive(bon::Builder))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TokenPair {
     pub remote_domain: u32,
     pub remote_token: solana_pubkey::Pubkey,
     pub local_token: solana_pubkey::Pubkey,
     pub bump: u8,
 }
+impl nitrogen_instruction_builder::AccountDeserialize for TokenPair {
+    const DISCRIMINATOR: &'static [u8] = &[17, 214, 45, 176, 229, 149, 197, 71];
+}
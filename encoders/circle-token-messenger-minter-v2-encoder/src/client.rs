@@ -0,0 +1,109 @@
+// @generated by nitrogen, do not edit.
+use {
+    crate::accounts::*,
+    nitrogen_instruction_builder::{
+        AccountDeserialize, client::{self, AccountFetcher, ClientResult, RpcFilterType},
+    },
+    solana_pubkey::Pubkey,
+};
+/// Fetches and decodes accounts owned by the program.
+pub struct TokenMessengerMinterV2Client<R: AccountFetcher> {
+    pub rpc: R,
+    pub program_id: Pubkey,
+}
+impl<R: AccountFetcher> TokenMessengerMinterV2Client<R> {
+    pub fn new(rpc: R) -> Self {
+        Self { rpc, program_id: crate::ID }
+    }
+    /// Targets a deployment of the program at `program_id` instead of
+    /// [`crate::ID`].
+    pub fn with_program_id(mut self, program_id: Pubkey) -> Self {
+        self.program_id = program_id;
+        self
+    }
+    /// Fetches several accounts of the same type, `None` for missing accounts.
+    pub async fn fetch_multiple<T: AccountDeserialize>(
+        &self,
+        addresses: &[Pubkey],
+    ) -> ClientResult<Vec<Option<T>>> {
+        client::fetch_multiple_accounts(&self.rpc, addresses).await
+    }
+    pub async fn fetch_denylisted_account(
+        &self,
+        address: &Pubkey,
+    ) -> ClientResult<DenylistedAccount> {
+        client::fetch_account(&self.rpc, address).await
+    }
+    pub async fn fetch_all_denylisted_account(
+        &self,
+        filters: Vec<RpcFilterType>,
+    ) -> ClientResult<Vec<(Pubkey, DenylistedAccount)>> {
+        client::fetch_program_accounts(&self.rpc, &self.program_id, filters).await
+    }
+    pub async fn fetch_local_token(&self, address: &Pubkey) -> ClientResult<LocalToken> {
+        client::fetch_account(&self.rpc, address).await
+    }
+    pub async fn fetch_all_local_token(
+        &self,
+        filters: Vec<RpcFilterType>,
+    ) -> ClientResult<Vec<(Pubkey, LocalToken)>> {
+        client::fetch_program_accounts(&self.rpc, &self.program_id, filters).await
+    }
+    pub async fn fetch_message_transmitter(
+        &self,
+        address: &Pubkey,
+    ) -> ClientResult<MessageTransmitter> {
+        client::fetch_account(&self.rpc, address).await
+    }
+    pub async fn fetch_all_message_transmitter(
+        &self,
+        filters: Vec<RpcFilterType>,
+    ) -> ClientResult<Vec<(Pubkey, MessageTransmitter)>> {
+        client::fetch_program_accounts(&self.rpc, &self.program_id, filters).await
+    }
+    pub async fn fetch_remote_token_messenger(
+        &self,
+        address: &Pubkey,
+    ) -> ClientResult<RemoteTokenMessenger> {
+        client::fetch_account(&self.rpc, address).await
+    }
+    pub async fn fetch_all_remote_token_messenger(
+        &self,
+        filters: Vec<RpcFilterType>,
+    ) -> ClientResult<Vec<(Pubkey, RemoteTokenMessenger)>> {
+        client::fetch_program_accounts(&self.rpc, &self.program_id, filters).await
+    }
+    pub async fn fetch_token_messenger(
+        &self,
+        address: &Pubkey,
+    ) -> ClientResult<TokenMessenger> {
+        client::fetch_account(&self.rpc, address).await
+    }
+    pub async fn fetch_all_token_messenger(
+        &self,
+        filters: Vec<RpcFilterType>,
+    ) -> ClientResult<Vec<(Pubkey, TokenMessenger)>> {
+        client::fetch_program_accounts(&self.rpc, &self.program_id, filters).await
+    }
+    pub async fn fetch_token_minter(
+        &self,
+        address: &Pubkey,
+    ) -> ClientResult<TokenMinter> {
+        client::fetch_account(&self.rpc, address).await
+    }
+    pub async fn fetch_all_token_minter(
+        &self,
+        filters: Vec<RpcFilterType>,
+    ) -> ClientResult<Vec<(Pubkey, TokenMinter)>> {
+        client::fetch_program_accounts(&self.rpc, &self.program_id, filters).await
+    }
+    pub async fn fetch_token_pair(&self, address: &Pubkey) -> ClientResult<TokenPair> {
+        client::fetch_account(&self.rpc, address).await
+    }
+    pub async fn fetch_all_token_pair(
+        &self,
+        filters: Vec<RpcFilterType>,
+    ) -> ClientResult<Vec<(Pubkey, TokenPair)>> {
+        client::fetch_program_accounts(&self.rpc, &self.program_id, filters).await
+    }
+}
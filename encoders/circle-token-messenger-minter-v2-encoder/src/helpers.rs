@@ -39,3 +39,48 @@ pub fn deposit_for_burn_instruction(
         crate::ID,
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accounts_with_program_id() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let params = DepositForBurnParams {
+            amount: 1,
+            destination_domain: 0,
+            mint_recipient: Pubkey::new_unique(),
+            destination_caller: Pubkey::default(),
+            max_fee: 0,
+            min_finality_threshold: 2000,
+        };
+        let instruction = deposit_for_burn(params)
+            .accounts_with_program_id(
+                program_id,
+                owner,
+                owner,
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                SOLANA_DEV_USDC_ADDRESS,
+                Pubkey::new_unique(),
+                program_id,
+            )
+            .instruction();
+        let pda = |seeds: &[&[u8]]| Pubkey::find_program_address(seeds, &program_id).0;
+
+        assert_eq!(instruction.program_id, program_id);
+        assert_eq!(instruction.accounts[2].pubkey, pda(&[b"sender_authority"]));
+        assert_eq!(
+            instruction.accounts[4].pubkey,
+            pda(&[b"denylist_account", owner.as_ref()])
+        );
+        assert_eq!(instruction.accounts[12].pubkey, MESSENGER_PROGRAM_ID);
+        assert_eq!(instruction.accounts[13].pubkey, program_id);
+        assert_eq!(instruction.accounts[16].pubkey, pda(&[b"__event_authority"]));
+    }
+}
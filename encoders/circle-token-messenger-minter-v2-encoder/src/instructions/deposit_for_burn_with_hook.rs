@@ -1,15 +1,14 @@
+// @generated by nitrogen, do not edit.
+use super::super::types::*;
 use {
-    super::super::types::*,
     nitrogen_instruction_builder::{InstructionBuilder, derive_pda},
-    solana_instruction::AccountMeta,
-    solana_pubkey::Pubkey,
+    solana_instruction::AccountMeta, solana_pubkey::Pubkey,
 };
-
-#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash)]
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DepositForBurnWithHook {
     pub params: DepositForBurnWithHookParams,
 }
-
 impl borsh::BorshSerialize for DepositForBurnWithHook {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         writer.write_all(&[111, 245, 62, 131, 204, 108, 223, 155])?;
@@ -17,7 +16,6 @@ impl borsh::BorshSerialize for DepositForBurnWithHook {
         Ok(())
     }
 }
-
 impl DepositForBurnWithHook {
     pub fn accounts(
         self,
@@ -31,50 +29,181 @@ impl DepositForBurnWithHook {
         burn_token_mint: Pubkey,
         message_sent_event_data: Pubkey,
         program: Pubkey,
+    ) -> InstructionBuilder<Self> {
+        self.accounts_with_program_id(
+            crate::ID,
+            owner,
+            event_rent_payer,
+            burn_token_account,
+            message_transmitter,
+            token_messenger,
+            remote_token_messenger,
+            token_minter,
+            burn_token_mint,
+            message_sent_event_data,
+            program,
+        )
+    }
+    /// Same as [`Self::accounts`], for a deployment of the program at
+    /// `__program_id`. PDAs are derived from `__program_id` and accounts fixed
+    /// to the program's own address are replaced by it.
+    pub fn accounts_with_program_id(
+        self,
+        __program_id: Pubkey,
+        owner: Pubkey,
+        event_rent_payer: Pubkey,
+        burn_token_account: Pubkey,
+        message_transmitter: Pubkey,
+        token_messenger: Pubkey,
+        remote_token_messenger: Pubkey,
+        token_minter: Pubkey,
+        burn_token_mint: Pubkey,
+        message_sent_event_data: Pubkey,
+        program: Pubkey,
     ) -> InstructionBuilder<Self> {
         let mut accounts: Vec<AccountMeta> = Vec::with_capacity(18);
         accounts.push(AccountMeta::new_readonly(owner, true));
         accounts.push(AccountMeta::new(event_rent_payer, true));
-        accounts.push(derive_pda(&[b"sender_authority"], &crate::ID, true));
+        accounts.push(derive_pda(&[b"sender_authority"], &__program_id, true));
         accounts.push(AccountMeta::new(burn_token_account, false));
-        accounts.push(derive_pda(
-            &[b"denylist_account", owner.as_ref()],
-            &crate::ID,
-            true,
-        ));
+        accounts
+            .push(
+                derive_pda(&[b"denylist_account", owner.as_ref()], &__program_id, true),
+            );
         accounts.push(AccountMeta::new(message_transmitter, false));
         accounts.push(AccountMeta::new_readonly(token_messenger, false));
         accounts.push(AccountMeta::new_readonly(remote_token_messenger, false));
         accounts.push(AccountMeta::new_readonly(token_minter, false));
-        accounts.push(derive_pda(
-            &[b"local_token", burn_token_mint.as_ref()],
-            &crate::ID,
-            false,
-        ));
+        accounts
+            .push(
+                derive_pda(
+                    &[b"local_token", burn_token_mint.as_ref()],
+                    &__program_id,
+                    false,
+                ),
+            );
         accounts.push(AccountMeta::new(burn_token_mint, false));
         accounts.push(AccountMeta::new(message_sent_event_data, true));
-        accounts.push(AccountMeta::new_readonly(
-            solana_pubkey::pubkey!("CCTPV2Sm4AdWt5296sk4P66VBZ7bEhcARwFaaS9YPbeC"),
-            false,
-        ));
-        accounts.push(AccountMeta::new_readonly(
-            solana_pubkey::pubkey!("CCTPV2vPZJS2u2BBsUoscuikbYjnpFmbFsvVuJdgUMQe"),
-            false,
-        ));
-        accounts.push(AccountMeta::new_readonly(
-            solana_pubkey::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
-            false,
-        ));
-        accounts.push(AccountMeta::new_readonly(
-            solana_pubkey::pubkey!("11111111111111111111111111111111"),
-            false,
-        ));
-        accounts.push(derive_pda(&[b"__event_authority"], &crate::ID, true));
+        accounts
+            .push(
+                AccountMeta::new_readonly(
+                    solana_pubkey::pubkey!(
+                        "CCTPV2Sm4AdWt5296sk4P66VBZ7bEhcARwFaaS9YPbeC"
+                    ),
+                    false,
+                ),
+            );
+        accounts.push(AccountMeta::new_readonly(__program_id, false));
+        accounts
+            .push(
+                AccountMeta::new_readonly(
+                    solana_pubkey::pubkey!(
+                        "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+                    ),
+                    false,
+                ),
+            );
+        accounts
+            .push(
+                AccountMeta::new_readonly(
+                    solana_pubkey::pubkey!("11111111111111111111111111111111"),
+                    false,
+                ),
+            );
+        accounts.push(derive_pda(&[b"__event_authority"], &__program_id, true));
         accounts.push(AccountMeta::new_readonly(program, false));
-        InstructionBuilder::builder()
-            .accounts(accounts)
-            .program_id(crate::ID)
-            .params(self)
-            .build()
+        InstructionBuilder::new(__program_id, accounts, self)
+    }
+}
+/// Invokes [`DepositForBurnWithHook`] from another program. Accounts are
+/// listed in IDL order.
+#[cfg(feature = "cpi")]
+pub struct DepositForBurnWithHookCpi<'a, 'info> {
+    /// The program being invoked.
+    pub __program: &'a solana_account_info::AccountInfo<'info>,
+    pub owner: &'a solana_account_info::AccountInfo<'info>,
+    pub event_rent_payer: &'a solana_account_info::AccountInfo<'info>,
+    pub sender_authority_pda: &'a solana_account_info::AccountInfo<'info>,
+    pub burn_token_account: &'a solana_account_info::AccountInfo<'info>,
+    pub denylist_account: &'a solana_account_info::AccountInfo<'info>,
+    pub message_transmitter: &'a solana_account_info::AccountInfo<'info>,
+    pub token_messenger: &'a solana_account_info::AccountInfo<'info>,
+    pub remote_token_messenger: &'a solana_account_info::AccountInfo<'info>,
+    pub token_minter: &'a solana_account_info::AccountInfo<'info>,
+    pub local_token: &'a solana_account_info::AccountInfo<'info>,
+    pub burn_token_mint: &'a solana_account_info::AccountInfo<'info>,
+    pub message_sent_event_data: &'a solana_account_info::AccountInfo<'info>,
+    pub message_transmitter_program: &'a solana_account_info::AccountInfo<'info>,
+    pub token_messenger_minter_program: &'a solana_account_info::AccountInfo<'info>,
+    pub token_program: &'a solana_account_info::AccountInfo<'info>,
+    pub system_program: &'a solana_account_info::AccountInfo<'info>,
+    pub event_authority: &'a solana_account_info::AccountInfo<'info>,
+    pub program: &'a solana_account_info::AccountInfo<'info>,
+    pub __data: DepositForBurnWithHook,
+}
+#[cfg(feature = "cpi")]
+impl<'a, 'info> DepositForBurnWithHookCpi<'a, 'info> {
+    pub fn invoke(&self) -> solana_program_error::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program_error::ProgramResult {
+        let mut accounts: Vec<AccountMeta> = Vec::with_capacity(18);
+        let mut account_infos = Vec::with_capacity(19);
+        account_infos.push(self.__program.clone());
+        accounts.push(AccountMeta::new_readonly(*self.owner.key, true));
+        account_infos.push(self.owner.clone());
+        accounts.push(AccountMeta::new(*self.event_rent_payer.key, true));
+        account_infos.push(self.event_rent_payer.clone());
+        accounts.push(AccountMeta::new_readonly(*self.sender_authority_pda.key, false));
+        account_infos.push(self.sender_authority_pda.clone());
+        accounts.push(AccountMeta::new(*self.burn_token_account.key, false));
+        account_infos.push(self.burn_token_account.clone());
+        accounts.push(AccountMeta::new_readonly(*self.denylist_account.key, false));
+        account_infos.push(self.denylist_account.clone());
+        accounts.push(AccountMeta::new(*self.message_transmitter.key, false));
+        account_infos.push(self.message_transmitter.clone());
+        accounts.push(AccountMeta::new_readonly(*self.token_messenger.key, false));
+        account_infos.push(self.token_messenger.clone());
+        accounts
+            .push(AccountMeta::new_readonly(*self.remote_token_messenger.key, false));
+        account_infos.push(self.remote_token_messenger.clone());
+        accounts.push(AccountMeta::new_readonly(*self.token_minter.key, false));
+        account_infos.push(self.token_minter.clone());
+        accounts.push(AccountMeta::new(*self.local_token.key, false));
+        account_infos.push(self.local_token.clone());
+        accounts.push(AccountMeta::new(*self.burn_token_mint.key, false));
+        account_infos.push(self.burn_token_mint.clone());
+        accounts.push(AccountMeta::new(*self.message_sent_event_data.key, true));
+        account_infos.push(self.message_sent_event_data.clone());
+        accounts
+            .push(
+                AccountMeta::new_readonly(*self.message_transmitter_program.key, false),
+            );
+        account_infos.push(self.message_transmitter_program.clone());
+        accounts
+            .push(
+                AccountMeta::new_readonly(
+                    *self.token_messenger_minter_program.key,
+                    false,
+                ),
+            );
+        account_infos.push(self.token_messenger_minter_program.clone());
+        accounts.push(AccountMeta::new_readonly(*self.token_program.key, false));
+        account_infos.push(self.token_program.clone());
+        accounts.push(AccountMeta::new_readonly(*self.system_program.key, false));
+        account_infos.push(self.system_program.clone());
+        accounts.push(AccountMeta::new_readonly(*self.event_authority.key, false));
+        account_infos.push(self.event_authority.clone());
+        accounts.push(AccountMeta::new_readonly(*self.program.key, false));
+        account_infos.push(self.program.clone());
+        let instruction = solana_instruction::Instruction::new_with_borsh(
+            *self.__program.key,
+            &self.__data,
+            accounts,
+        );
+        solana_cpi::invoke_signed(&instruction, &account_infos, signers_seeds)
     }
 }
@@ -1,20 +1,24 @@
+// @generated by nitrogen, do not edit.
+use super::types::*;
 pub mod deposit_for_burn;
 pub mod deposit_for_burn_with_hook;
-
-#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug, Clone, Hash)]
+#[derive(PartialEq, Eq, Debug, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TokenMessengerMinterV2Instruction {
     DepositForBurn(deposit_for_burn::DepositForBurn),
     DepositForBurnWithHook(deposit_for_burn_with_hook::DepositForBurnWithHook),
 }
-
 pub fn deposit_for_burn(
-    params: crate::types::DepositForBurnParams,
+    params: DepositForBurnParams,
 ) -> deposit_for_burn::DepositForBurn {
-    deposit_for_burn::DepositForBurn { params }
+    deposit_for_burn::DepositForBurn {
+        params,
+    }
 }
-
 pub fn deposit_for_burn_with_hook(
-    params: crate::types::DepositForBurnWithHookParams,
+    params: DepositForBurnWithHookParams,
 ) -> deposit_for_burn_with_hook::DepositForBurnWithHook {
-    deposit_for_burn_with_hook::DepositForBurnWithHook { params }
+    deposit_for_burn_with_hook::DepositForBurnWithHook {
+        params,
+    }
 }
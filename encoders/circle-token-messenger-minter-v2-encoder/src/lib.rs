@@ -1,8 +1,10 @@
+// @generated by nitrogen, do not edit.
 use solana_pubkey::declare_id;
 pub struct TokenMessengerMinterV2Encoder;
 pub mod accounts;
-pub mod helpers;
 pub mod instructions;
 pub mod types;
-
+pub mod helpers;
+#[cfg(feature = "client")]
+pub mod client;
 declare_id!("CCTPV2vPZJS2u2BBsUoscuikbYjnpFmbFsvVuJdgUMQe");
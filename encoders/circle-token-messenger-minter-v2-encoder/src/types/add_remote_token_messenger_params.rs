@@ -1,16 +0,0 @@
-#[derive(
-    Debug,
-    borsh::BorshSerialize,
-    borsh::BorshDeserialize,
-    bon::Builder,
-    serde::Serialize,
-    serde::Deserialize,
-    PartialEq,
-    Eq,
-    Clone,
-    Hash,
-)]
-pub struct AddRemoteTokenMessengerParams {
-    pub domain: u32,
-    pub token_messenger: solana_pubkey::Pubkey,
-}
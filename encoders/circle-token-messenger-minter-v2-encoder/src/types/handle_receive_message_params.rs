@@ -1,19 +0,0 @@
-#[derive(
-    Debug,
-    borsh::BorshSerialize,
-    borsh::BorshDeserialize,
-    bon::Builder,
-    serde::Serialize,
-    serde::Deserialize,
-    PartialEq,
-    Eq,
-    Clone,
-    Hash,
-)]
-pub struct HandleReceiveMessageParams {
-    pub remote_domain: u32,
-    pub sender: solana_pubkey::Pubkey,
-    pub finality_threshold_executed: u32,
-    pub message_body: Vec<u8>,
-    pub authority_bump: u8,
-}
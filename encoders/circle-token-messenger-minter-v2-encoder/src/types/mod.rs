@@ -1,106 +1,19 @@
-pub mod accept_ownership_params;
-pub use accept_ownership_params::*;
-pub mod add_local_token_params;
-pub use add_local_token_params::*;
-pub mod add_remote_token_messenger_params;
-pub use add_remote_token_messenger_params::*;
-pub mod burn_token_custody_params;
-pub use burn_token_custody_params::*;
-pub mod denylist_params;
-pub use denylist_params::*;
-pub mod denylisted;
-pub use denylisted::*;
+// @generated by nitrogen, do not edit.
 pub mod denylisted_account;
 pub use denylisted_account::*;
-pub mod denylister_changed;
-pub use denylister_changed::*;
-pub mod deposit_for_burn;
-pub use deposit_for_burn::*;
 pub mod deposit_for_burn_params;
 pub use deposit_for_burn_params::*;
 pub mod deposit_for_burn_with_hook_params;
 pub use deposit_for_burn_with_hook_params::*;
-pub mod fee_recipient_set;
-pub use fee_recipient_set::*;
-pub mod handle_receive_message_params;
-pub use handle_receive_message_params::*;
-pub mod initialize_params;
-pub use initialize_params::*;
-pub mod link_token_pair_params;
-pub use link_token_pair_params::*;
 pub mod local_token;
 pub use local_token::*;
-pub mod local_token_added;
-pub use local_token_added::*;
-pub mod local_token_removed;
-pub use local_token_removed::*;
 pub mod message_transmitter;
 pub use message_transmitter::*;
-pub mod min_fee_controller_set;
-pub use min_fee_controller_set::*;
-pub mod min_fee_set;
-pub use min_fee_set::*;
-pub mod mint_and_withdraw;
-pub use mint_and_withdraw::*;
-pub mod ownership_transfer_started;
-pub use ownership_transfer_started::*;
-pub mod ownership_transferred;
-pub use ownership_transferred::*;
-pub mod pause;
-pub use pause::*;
-pub mod pause_params;
-pub use pause_params::*;
-pub mod pauser_changed;
-pub use pauser_changed::*;
 pub mod remote_token_messenger;
 pub use remote_token_messenger::*;
-pub mod remote_token_messenger_added;
-pub use remote_token_messenger_added::*;
-pub mod remote_token_messenger_removed;
-pub use remote_token_messenger_removed::*;
-pub mod remove_local_token_params;
-pub use remove_local_token_params::*;
-pub mod remove_remote_token_messenger_params;
-pub use remove_remote_token_messenger_params::*;
-pub mod set_burn_limit_per_message;
-pub use set_burn_limit_per_message::*;
-pub mod set_fee_recipient_params;
-pub use set_fee_recipient_params::*;
-pub mod set_max_burn_amount_per_message_params;
-pub use set_max_burn_amount_per_message_params::*;
-pub mod set_min_fee_controller_params;
-pub use set_min_fee_controller_params::*;
-pub mod set_min_fee_params;
-pub use set_min_fee_params::*;
-pub mod set_token_controller;
-pub use set_token_controller::*;
-pub mod set_token_controller_params;
-pub use set_token_controller_params::*;
-pub mod token_custody_burned;
-pub use token_custody_burned::*;
 pub mod token_messenger;
 pub use token_messenger::*;
 pub mod token_minter;
 pub use token_minter::*;
 pub mod token_pair;
 pub use token_pair::*;
-pub mod token_pair_linked;
-pub use token_pair_linked::*;
-pub mod token_pair_unlinked;
-pub use token_pair_unlinked::*;
-pub mod transfer_ownership_params;
-pub use transfer_ownership_params::*;
-pub mod un_denylisted;
-pub use un_denylisted::*;
-pub mod undenylist_params;
-pub use undenylist_params::*;
-pub mod unink_token_pair_params;
-pub use unink_token_pair_params::*;
-pub mod unpause;
-pub use unpause::*;
-pub mod unpause_params;
-pub use unpause_params::*;
-pub mod update_denylister_params;
-pub use update_denylister_params::*;
-pub mod update_pauser_params;
-pub use update_pauser_params::*;
@@ -0,0 +1,21 @@
+// @generated by nitrogen, do not edit.
+use super::super::types::*;
+#[derive(
+    Debug,
+    borsh::BorshSerialize,
+    borsh::BorshDeserialize,
+    PartialEq,
+    Eq,
+    Clone,
+    Hash,
+)]
+#[cfg_attr(feature = "builder", derive(bon::Builder))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Counter {
+    pub authority: solana_pubkey::Pubkey,
+    pub value: u64,
+    pub config: CounterConfig,
+}
+impl nitrogen_instruction_builder::AccountDeserialize for Counter {
+    const DISCRIMINATOR: &'static [u8] = &[1];
+}
@@ -0,0 +1,19 @@
+// @generated by nitrogen, do not edit.
+#[derive(
+    Debug,
+    borsh::BorshSerialize,
+    borsh::BorshDeserialize,
+    PartialEq,
+    Default,
+    Eq,
+    Clone,
+    Hash,
+)]
+#[cfg_attr(feature = "builder", derive(bon::Builder))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Marker {
+    pub owner: solana_pubkey::Pubkey,
+}
+impl nitrogen_instruction_builder::AccountDeserialize for Marker {
+    const DISCRIMINATOR: &'static [u8] = &[7, 7, 7, 7];
+}
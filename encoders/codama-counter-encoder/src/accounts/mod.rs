@@ -0,0 +1,33 @@
+// @generated by nitrogen, do not edit.
+pub mod counter;
+pub use counter::*;
+pub mod receipt;
+pub use receipt::*;
+pub mod marker;
+pub use marker::*;
+pub enum CounterAccount {
+    Counter(counter::Counter),
+    Receipt(receipt::Receipt),
+    Marker(marker::Marker),
+}
+impl CounterAccount {
+    /// Decodes account data as the first account type it matches.
+    pub fn try_from_account_data(data: &[u8]) -> std::io::Result<Self> {
+        use nitrogen_instruction_builder::AccountDeserialize;
+        if counter::Counter::matches_account_data(data) {
+            return Ok(Self::Counter(counter::Counter::try_from_account_data(data)?));
+        }
+        if receipt::Receipt::matches_account_data(data) {
+            return Ok(Self::Receipt(receipt::Receipt::try_from_account_data(data)?));
+        }
+        if marker::Marker::matches_account_data(data) {
+            return Ok(Self::Marker(marker::Marker::try_from_account_data(data)?));
+        }
+        Err(
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "unknown account discriminator",
+            ),
+        )
+    }
+}
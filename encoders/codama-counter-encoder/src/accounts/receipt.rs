@@ -0,0 +1,22 @@
+// @generated by nitrogen, do not edit.
+#[derive(
+    Debug,
+    borsh::BorshSerialize,
+    borsh::BorshDeserialize,
+    PartialEq,
+    Default,
+    Eq,
+    Clone,
+    Hash,
+)]
+#[cfg_attr(feature = "builder", derive(bon::Builder))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Receipt {
+    pub amount: u64,
+    pub kind: u8,
+}
+impl nitrogen_instruction_builder::AccountDeserialize for Receipt {
+    const DISCRIMINATOR: &'static [u8] = &[2];
+    const DISCRIMINATOR_OFFSET: usize = 8;
+    const SIZE: Option<usize> = Some(9);
+}
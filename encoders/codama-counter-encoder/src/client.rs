@@ -0,0 +1,58 @@
+// @generated by nitrogen, do not edit.
+use {
+    crate::accounts::*,
+    nitrogen_instruction_builder::{
+        AccountDeserialize, client::{self, AccountFetcher, ClientResult, RpcFilterType},
+    },
+    solana_pubkey::Pubkey,
+};
+/// Fetches and decodes accounts owned by the program.
+pub struct CounterClient<R: AccountFetcher> {
+    pub rpc: R,
+    pub program_id: Pubkey,
+}
+impl<R: AccountFetcher> CounterClient<R> {
+    pub fn new(rpc: R) -> Self {
+        Self { rpc, program_id: crate::ID }
+    }
+    /// Targets a deployment of the program at `program_id` instead of
+    /// [`crate::ID`].
+    pub fn with_program_id(mut self, program_id: Pubkey) -> Self {
+        self.program_id = program_id;
+        self
+    }
+    /// Fetches several accounts of the same type, `None` for missing accounts.
+    pub async fn fetch_multiple<T: AccountDeserialize>(
+        &self,
+        addresses: &[Pubkey],
+    ) -> ClientResult<Vec<Option<T>>> {
+        client::fetch_multiple_accounts(&self.rpc, addresses).await
+    }
+    pub async fn fetch_counter(&self, address: &Pubkey) -> ClientResult<Counter> {
+        client::fetch_account(&self.rpc, address).await
+    }
+    pub async fn fetch_all_counter(
+        &self,
+        filters: Vec<RpcFilterType>,
+    ) -> ClientResult<Vec<(Pubkey, Counter)>> {
+        client::fetch_program_accounts(&self.rpc, &self.program_id, filters).await
+    }
+    pub async fn fetch_receipt(&self, address: &Pubkey) -> ClientResult<Receipt> {
+        client::fetch_account(&self.rpc, address).await
+    }
+    pub async fn fetch_all_receipt(
+        &self,
+        filters: Vec<RpcFilterType>,
+    ) -> ClientResult<Vec<(Pubkey, Receipt)>> {
+        client::fetch_program_accounts(&self.rpc, &self.program_id, filters).await
+    }
+    pub async fn fetch_marker(&self, address: &Pubkey) -> ClientResult<Marker> {
+        client::fetch_account(&self.rpc, address).await
+    }
+    pub async fn fetch_all_marker(
+        &self,
+        filters: Vec<RpcFilterType>,
+    ) -> ClientResult<Vec<(Pubkey, Marker)>> {
+        client::fetch_program_accounts(&self.rpc, &self.program_id, filters).await
+    }
+}
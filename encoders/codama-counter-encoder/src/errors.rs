@@ -0,0 +1,41 @@
+// @generated by nitrogen, do not edit.
+/// Errors returned by the program.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u32)]
+pub enum CounterError {
+    /// The authority does not own the counter
+    InvalidAuthority = 6000,
+    /// Counter "value" overflowed
+    Overflow = 6001,
+}
+impl CounterError {
+    pub fn from_code(code: u32) -> Option<Self> {
+        match code {
+            6000 => Some(Self::InvalidAuthority),
+            6001 => Some(Self::Overflow),
+            _ => None,
+        }
+    }
+    pub fn code(self) -> u32 {
+        self as u32
+    }
+    pub fn message(self) -> &'static str {
+        match self {
+            Self::InvalidAuthority => "The authority does not own the counter",
+            Self::Overflow => "Counter \"value\" overflowed",
+        }
+    }
+}
+impl std::fmt::Display for CounterError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.message())
+    }
+}
+impl std::error::Error for CounterError {}
+#[cfg(feature = "cpi")]
+impl From<CounterError> for solana_program_error::ProgramError {
+    fn from(error: CounterError) -> Self {
+        solana_program_error::ProgramError::Custom(error.code())
+    }
+}
@@ -0,0 +1,75 @@
+// @generated by nitrogen, do not edit.
+use {
+    nitrogen_instruction_builder::InstructionBuilder,
+    solana_instruction::AccountMeta, solana_pubkey::Pubkey,
+};
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Close {
+    pub reason: u8,
+}
+impl borsh::BorshSerialize for Close {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&[202, 254])?;
+        self.reason.serialize(writer)?;
+        writer.write_all(&[118, 49])?;
+        Ok(())
+    }
+}
+impl Close {
+    pub fn accounts(
+        self,
+        counter: Pubkey,
+        authority: Pubkey,
+    ) -> InstructionBuilder<Self> {
+        self.accounts_with_program_id(crate::ID, counter, authority)
+    }
+    /// Same as [`Self::accounts`], for a deployment of the program at
+    /// `__program_id`. PDAs are derived from `__program_id` and accounts fixed
+    /// to the program's own address are replaced by it.
+    pub fn accounts_with_program_id(
+        self,
+        __program_id: Pubkey,
+        counter: Pubkey,
+        authority: Pubkey,
+    ) -> InstructionBuilder<Self> {
+        let mut accounts: Vec<AccountMeta> = Vec::with_capacity(2);
+        accounts.push(AccountMeta::new(counter, false));
+        accounts.push(AccountMeta::new_readonly(authority, true));
+        InstructionBuilder::new(__program_id, accounts, self)
+    }
+}
+/// Invokes [`Close`] from another program. Accounts are
+/// listed in IDL order.
+#[cfg(feature = "cpi")]
+pub struct CloseCpi<'a, 'info> {
+    /// The program being invoked.
+    pub __program: &'a solana_account_info::AccountInfo<'info>,
+    pub counter: &'a solana_account_info::AccountInfo<'info>,
+    pub authority: &'a solana_account_info::AccountInfo<'info>,
+    pub __data: Close,
+}
+#[cfg(feature = "cpi")]
+impl<'a, 'info> CloseCpi<'a, 'info> {
+    pub fn invoke(&self) -> solana_program_error::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program_error::ProgramResult {
+        let mut accounts: Vec<AccountMeta> = Vec::with_capacity(2);
+        let mut account_infos = Vec::with_capacity(3);
+        account_infos.push(self.__program.clone());
+        accounts.push(AccountMeta::new(*self.counter.key, false));
+        account_infos.push(self.counter.clone());
+        accounts.push(AccountMeta::new_readonly(*self.authority.key, true));
+        account_infos.push(self.authority.clone());
+        let instruction = solana_instruction::Instruction::new_with_borsh(
+            *self.__program.key,
+            &self.__data,
+            accounts,
+        );
+        solana_cpi::invoke_signed(&instruction, &account_infos, signers_seeds)
+    }
+}
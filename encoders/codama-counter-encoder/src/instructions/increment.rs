@@ -0,0 +1,112 @@
+// @generated by nitrogen, do not edit.
+use {
+    nitrogen_instruction_builder::{InstructionBuilder, derive_pda},
+    solana_instruction::AccountMeta, solana_pubkey::Pubkey,
+};
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Increment {
+    pub amount: u64,
+}
+impl borsh::BorshSerialize for Increment {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&[1])?;
+        self.amount.serialize(writer)?;
+        Ok(())
+    }
+}
+impl Increment {
+    pub fn accounts(
+        self,
+        authority: Pubkey,
+        delegate: (Pubkey, bool),
+        referrer: Option<(Pubkey, bool)>,
+    ) -> InstructionBuilder<Self> {
+        self.accounts_with_program_id(crate::ID, authority, delegate, referrer)
+    }
+    /// Same as [`Self::accounts`], for a deployment of the program at
+    /// `__program_id`. PDAs are derived from `__program_id` and accounts fixed
+    /// to the program's own address are replaced by it.
+    pub fn accounts_with_program_id(
+        self,
+        __program_id: Pubkey,
+        authority: Pubkey,
+        delegate: (Pubkey, bool),
+        referrer: Option<(Pubkey, bool)>,
+    ) -> InstructionBuilder<Self> {
+        let mut accounts: Vec<AccountMeta> = Vec::with_capacity(6);
+        accounts
+            .push(derive_pda(&[b"counter", authority.as_ref()], &__program_id, false));
+        accounts.push(AccountMeta::new_readonly(authority, true));
+        accounts
+            .push(
+                derive_pda(
+                    &[b"receipt", authority.as_ref(), &self.amount.to_le_bytes()],
+                    &__program_id,
+                    false,
+                ),
+            );
+        accounts.push(AccountMeta::new_readonly(__program_id, false));
+        accounts.push(AccountMeta::new_readonly(delegate.0, delegate.1));
+        if let Some((pk, is_signer)) = referrer {
+            accounts.push(AccountMeta::new(pk, is_signer));
+        } else {
+            accounts.push(AccountMeta::new_readonly(__program_id, false));
+        }
+        InstructionBuilder::new(__program_id, accounts, self)
+    }
+}
+/// Invokes [`Increment`] from another program. Accounts are
+/// listed in IDL order.
+#[cfg(feature = "cpi")]
+pub struct IncrementCpi<'a, 'info> {
+    /// The program being invoked.
+    pub __program: &'a solana_account_info::AccountInfo<'info>,
+    pub counter: &'a solana_account_info::AccountInfo<'info>,
+    pub authority: &'a solana_account_info::AccountInfo<'info>,
+    pub receipt: &'a solana_account_info::AccountInfo<'info>,
+    pub counter_program: &'a solana_account_info::AccountInfo<'info>,
+    pub delegate: &'a solana_account_info::AccountInfo<'info>,
+    pub referrer: Option<&'a solana_account_info::AccountInfo<'info>>,
+    pub __data: Increment,
+}
+#[cfg(feature = "cpi")]
+impl<'a, 'info> IncrementCpi<'a, 'info> {
+    pub fn invoke(&self) -> solana_program_error::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program_error::ProgramResult {
+        let mut accounts: Vec<AccountMeta> = Vec::with_capacity(6);
+        let mut account_infos = Vec::with_capacity(7);
+        account_infos.push(self.__program.clone());
+        accounts.push(AccountMeta::new(*self.counter.key, false));
+        account_infos.push(self.counter.clone());
+        accounts.push(AccountMeta::new_readonly(*self.authority.key, true));
+        account_infos.push(self.authority.clone());
+        accounts.push(AccountMeta::new(*self.receipt.key, false));
+        account_infos.push(self.receipt.clone());
+        accounts.push(AccountMeta::new_readonly(*self.counter_program.key, false));
+        account_infos.push(self.counter_program.clone());
+        accounts
+            .push(
+                AccountMeta::new_readonly(*self.delegate.key, self.delegate.is_signer),
+            );
+        account_infos.push(self.delegate.clone());
+        if let Some(account_info) = self.referrer {
+            accounts.push(AccountMeta::new(*account_info.key, account_info.is_signer));
+            account_infos.push(account_info.clone());
+        } else {
+            accounts.push(AccountMeta::new_readonly(*self.__program.key, false));
+            account_infos.push(self.__program.clone());
+        }
+        let instruction = solana_instruction::Instruction::new_with_borsh(
+            *self.__program.key,
+            &self.__data,
+            accounts,
+        );
+        solana_cpi::invoke_signed(&instruction, &account_infos, signers_seeds)
+    }
+}
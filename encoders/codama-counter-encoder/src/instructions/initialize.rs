@@ -0,0 +1,85 @@
+// @generated by nitrogen, do not edit.
+use super::super::types::*;
+use {
+    nitrogen_instruction_builder::{InstructionBuilder, derive_pda},
+    solana_instruction::AccountMeta, solana_pubkey::Pubkey,
+};
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Initialize {
+    pub config: CounterConfig,
+}
+impl borsh::BorshSerialize for Initialize {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&[0])?;
+        self.config.serialize(writer)?;
+        Ok(())
+    }
+}
+impl Initialize {
+    pub fn accounts(self, authority: Pubkey) -> InstructionBuilder<Self> {
+        self.accounts_with_program_id(crate::ID, authority)
+    }
+    /// Same as [`Self::accounts`], for a deployment of the program at
+    /// `__program_id`. PDAs are derived from `__program_id` and accounts fixed
+    /// to the program's own address are replaced by it.
+    pub fn accounts_with_program_id(
+        self,
+        __program_id: Pubkey,
+        authority: Pubkey,
+    ) -> InstructionBuilder<Self> {
+        let mut accounts: Vec<AccountMeta> = Vec::with_capacity(4);
+        accounts
+            .push(derive_pda(&[b"counter", authority.as_ref()], &__program_id, false));
+        accounts.push(AccountMeta::new_readonly(authority, true));
+        accounts.push(AccountMeta::new(authority, true));
+        accounts
+            .push(
+                AccountMeta::new_readonly(
+                    solana_pubkey::pubkey!("11111111111111111111111111111111"),
+                    false,
+                ),
+            );
+        InstructionBuilder::new(__program_id, accounts, self)
+    }
+}
+/// Invokes [`Initialize`] from another program. Accounts are
+/// listed in IDL order.
+#[cfg(feature = "cpi")]
+pub struct InitializeCpi<'a, 'info> {
+    /// The program being invoked.
+    pub __program: &'a solana_account_info::AccountInfo<'info>,
+    pub counter: &'a solana_account_info::AccountInfo<'info>,
+    pub authority: &'a solana_account_info::AccountInfo<'info>,
+    pub payer: &'a solana_account_info::AccountInfo<'info>,
+    pub system_program: &'a solana_account_info::AccountInfo<'info>,
+    pub __data: Initialize,
+}
+#[cfg(feature = "cpi")]
+impl<'a, 'info> InitializeCpi<'a, 'info> {
+    pub fn invoke(&self) -> solana_program_error::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program_error::ProgramResult {
+        let mut accounts: Vec<AccountMeta> = Vec::with_capacity(4);
+        let mut account_infos = Vec::with_capacity(5);
+        account_infos.push(self.__program.clone());
+        accounts.push(AccountMeta::new(*self.counter.key, false));
+        account_infos.push(self.counter.clone());
+        accounts.push(AccountMeta::new_readonly(*self.authority.key, true));
+        account_infos.push(self.authority.clone());
+        accounts.push(AccountMeta::new(*self.payer.key, true));
+        account_infos.push(self.payer.clone());
+        accounts.push(AccountMeta::new_readonly(*self.system_program.key, false));
+        account_infos.push(self.system_program.clone());
+        let instruction = solana_instruction::Instruction::new_with_borsh(
+            *self.__program.key,
+            &self.__data,
+            accounts,
+        );
+        solana_cpi::invoke_signed(&instruction, &account_infos, signers_seeds)
+    }
+}
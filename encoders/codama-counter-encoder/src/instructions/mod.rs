@@ -0,0 +1,21 @@
+// @generated by nitrogen, do not edit.
+use super::types::*;
+pub mod initialize;
+pub mod increment;
+pub mod close;
+#[derive(PartialEq, Eq, Debug, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CounterInstruction {
+    Initialize(initialize::Initialize),
+    Increment(increment::Increment),
+    Close(close::Close),
+}
+pub fn initialize(config: CounterConfig) -> initialize::Initialize {
+    initialize::Initialize { config }
+}
+pub fn increment(amount: u64) -> increment::Increment {
+    increment::Increment { amount }
+}
+pub fn close(reason: u8) -> close::Close {
+    close::Close { reason }
+}
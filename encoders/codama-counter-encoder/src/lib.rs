@@ -0,0 +1,11 @@
+// @generated by nitrogen, do not edit.
+use solana_pubkey::declare_id;
+pub struct CounterEncoder;
+pub mod accounts;
+pub mod instructions;
+pub mod types;
+pub mod errors;
+pub mod helpers;
+#[cfg(feature = "client")]
+pub mod client;
+declare_id!("CounterProgram11111111111111111111111111111");
@@ -0,0 +1,16 @@
+// @generated by nitrogen, do not edit.
+#[derive(
+    Debug,
+    borsh::BorshSerialize,
+    borsh::BorshDeserialize,
+    PartialEq,
+    Eq,
+    Clone,
+    Hash
+)]
+#[cfg_attr(feature = "builder", derive(bon::Builder))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CounterClosed {
+    pub discriminator: [u8; 2],
+    pub authority: solana_pubkey::Pubkey,
+}
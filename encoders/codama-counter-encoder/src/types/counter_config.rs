@@ -0,0 +1,18 @@
+// @generated by nitrogen, do not edit.
+use super::*;
+#[derive(
+    Debug,
+    borsh::BorshSerialize,
+    borsh::BorshDeserialize,
+    PartialEq,
+    Eq,
+    Clone,
+    Hash
+)]
+#[cfg_attr(feature = "builder", derive(bon::Builder))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CounterConfig {
+    pub max_value: u64,
+    pub label: nitrogen_instruction_builder::codec::RemainderString,
+    pub mode: CounterMode,
+}
@@ -0,0 +1,18 @@
+// @generated by nitrogen, do not edit.
+use super::*;
+#[derive(
+    Debug,
+    borsh::BorshSerialize,
+    borsh::BorshDeserialize,
+    PartialEq,
+    Eq,
+    Clone,
+    Hash
+)]
+#[cfg_attr(feature = "builder", derive(bon::Builder))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CounterIncremented {
+    pub authority: solana_pubkey::Pubkey,
+    pub amount: u64,
+    pub mode: CounterMode,
+}
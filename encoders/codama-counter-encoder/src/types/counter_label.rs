@@ -0,0 +1,34 @@
+// @generated by nitrogen, do not edit.
+#[derive(
+    Debug,
+    borsh::BorshSerialize,
+    borsh::BorshDeserialize,
+    PartialEq,
+    Eq,
+    Clone,
+    Hash
+)]
+#[cfg_attr(feature = "builder", derive(bon::Builder))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CounterLabel {
+    pub id: nitrogen_instruction_builder::codec::BigEndian<u32>,
+    pub name: nitrogen_instruction_builder::codec::PrefixedString<u8>,
+    pub symbol: nitrogen_instruction_builder::codec::FixedString<8>,
+    pub seed: [u8; 4],
+    pub version: nitrogen_instruction_builder::codec::Padded<4, u16>,
+    pub tags: nitrogen_instruction_builder::codec::PrefixedVec<u16, u8>,
+    pub flags: nitrogen_instruction_builder::codec::PrePadded<2, u8>,
+    pub delegate: nitrogen_instruction_builder::codec::ZeroableOption<
+        solana_pubkey::Pubkey,
+    >,
+    pub limit: nitrogen_instruction_builder::codec::PrefixedOption<u32, u64>,
+    pub index: nitrogen_instruction_builder::codec::ShortU16,
+    pub aliases: nitrogen_instruction_builder::codec::PrefixedVec<
+        nitrogen_instruction_builder::codec::ShortU16,
+        u8,
+    >,
+    pub memo: nitrogen_instruction_builder::codec::PrefixedString<
+        nitrogen_instruction_builder::codec::BigEndian<u32>,
+    >,
+    pub payload: nitrogen_instruction_builder::codec::RemainderVec<u8>,
+}
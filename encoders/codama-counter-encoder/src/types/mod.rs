@@ -0,0 +1,11 @@
+// @generated by nitrogen, do not edit.
+pub mod counter_config;
+pub use counter_config::*;
+pub mod counter_mode;
+pub use counter_mode::*;
+pub mod counter_label;
+pub use counter_label::*;
+pub mod counter_incremented;
+pub use counter_incremented::*;
+pub mod counter_closed;
+pub use counter_closed::*;
@@ -0,0 +1,2 @@
+// @generated by nitrogen, do not edit.
+pub enum MemoAccount {}
@@ -0,0 +1,30 @@
+// @generated by nitrogen, do not edit.
+use {
+    nitrogen_instruction_builder::{
+        AccountDeserialize, client::{self, AccountFetcher, ClientResult},
+    },
+    solana_pubkey::Pubkey,
+};
+/// Fetches and decodes accounts owned by the program.
+pub struct MemoClient<R: AccountFetcher> {
+    pub rpc: R,
+    pub program_id: Pubkey,
+}
+impl<R: AccountFetcher> MemoClient<R> {
+    pub fn new(rpc: R) -> Self {
+        Self { rpc, program_id: crate::ID }
+    }
+    /// Targets a deployment of the program at `program_id` instead of
+    /// [`crate::ID`].
+    pub fn with_program_id(mut self, program_id: Pubkey) -> Self {
+        self.program_id = program_id;
+        self
+    }
+    /// Fetches several accounts of the same type, `None` for missing accounts.
+    pub async fn fetch_multiple<T: AccountDeserialize>(
+        &self,
+        addresses: &[Pubkey],
+    ) -> ClientResult<Vec<Option<T>>> {
+        client::fetch_multiple_accounts(&self.rpc, addresses).await
+    }
+}
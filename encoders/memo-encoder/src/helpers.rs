@@ -0,0 +1 @@
+// custom helper utilities for instructions
\ No newline at end of file
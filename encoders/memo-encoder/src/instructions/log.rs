@@ -0,0 +1,65 @@
+// @generated by nitrogen, do not edit.
+use {
+    nitrogen_instruction_builder::InstructionBuilder,
+    solana_instruction::AccountMeta, solana_pubkey::Pubkey,
+};
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Log {
+    pub message: String,
+}
+impl borsh::BorshSerialize for Log {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&[141, 230, 214, 242, 9, 209, 207, 170])?;
+        self.message.serialize(writer)?;
+        Ok(())
+    }
+}
+impl Log {
+    pub fn accounts(self, signer: Pubkey) -> InstructionBuilder<Self> {
+        self.accounts_with_program_id(crate::ID, signer)
+    }
+    /// Same as [`Self::accounts`], for a deployment of the program at
+    /// `__program_id`. PDAs are derived from `__program_id` and accounts fixed
+    /// to the program's own address are replaced by it.
+    pub fn accounts_with_program_id(
+        self,
+        __program_id: Pubkey,
+        signer: Pubkey,
+    ) -> InstructionBuilder<Self> {
+        let mut accounts: Vec<AccountMeta> = Vec::with_capacity(1);
+        accounts.push(AccountMeta::new_readonly(signer, true));
+        InstructionBuilder::new(__program_id, accounts, self)
+    }
+}
+/// Invokes [`Log`] from another program. Accounts are
+/// listed in IDL order.
+#[cfg(feature = "cpi")]
+pub struct LogCpi<'a, 'info> {
+    /// The program being invoked.
+    pub __program: &'a solana_account_info::AccountInfo<'info>,
+    pub signer: &'a solana_account_info::AccountInfo<'info>,
+    pub __data: Log,
+}
+#[cfg(feature = "cpi")]
+impl<'a, 'info> LogCpi<'a, 'info> {
+    pub fn invoke(&self) -> solana_program_error::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program_error::ProgramResult {
+        let mut accounts: Vec<AccountMeta> = Vec::with_capacity(1);
+        let mut account_infos = Vec::with_capacity(2);
+        account_infos.push(self.__program.clone());
+        accounts.push(AccountMeta::new_readonly(*self.signer.key, true));
+        account_infos.push(self.signer.clone());
+        let instruction = solana_instruction::Instruction::new_with_borsh(
+            *self.__program.key,
+            &self.__data,
+            accounts,
+        );
+        solana_cpi::invoke_signed(&instruction, &account_infos, signers_seeds)
+    }
+}
@@ -0,0 +1,10 @@
+// @generated by nitrogen, do not edit.
+pub mod log;
+#[derive(PartialEq, Eq, Debug, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MemoInstruction {
+    Log(log::Log),
+}
+pub fn log(message: String) -> log::Log {
+    log::Log { message }
+}
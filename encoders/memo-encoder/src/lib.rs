@@ -0,0 +1,10 @@
+// @generated by nitrogen, do not edit.
+use solana_pubkey::declare_id;
+pub struct MemoEncoder;
+pub mod accounts;
+pub mod instructions;
+pub mod types;
+pub mod helpers;
+#[cfg(feature = "client")]
+pub mod client;
+declare_id!("Memo111111111111111111111111111111111111111");
@@ -0,0 +1 @@
+// @generated by nitrogen, do not edit.
@@ -53,7 +53,8 @@ async fn reclaim<T: TransactionRpcProvider + AsRef<RpcClient>>(
     owner: Keypair,
 ) -> Result<()> {
     let reclaim_accounts =
-        reclaim_event_account_helpers::find_claimable_accounts(&owner.pubkey(), rpc).await?;
+        reclaim_event_account_helpers::find_claimable_accounts(&owner.pubkey(), rpc.as_ref())
+            .await?;
     info!("reclaim accounts {reclaim_accounts}");
     let mut fee: Option<u64> = None;
     let mut units: Option<u32> = None;
@@ -271,9 +272,11 @@ pub async fn main() -> Result<()> {
                 attest,
                 message,
             );
-            let fee_recipient =
-                receive_message_helpers::fee_recipient_token_account(&rpc, &SOLANA_USDC_ADDRESS)
-                    .await?;
+            let fee_recipient = receive_message_helpers::fee_recipient_token_account(
+                rpc.as_ref(),
+                &SOLANA_USDC_ADDRESS,
+            )
+            .await?;
             let usdc_evm_addr: Address =
                 alloy_primitives::address!("0x036CbD53842c5426634e7929541eC2318f3dCF7e"); // base sepolia
             let remaining_accounts = receive_message_helpers::remaining_accounts(